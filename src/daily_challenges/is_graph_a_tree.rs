@@ -1,5 +1,8 @@
 use std::collections::HashSet;
 
+use crate::graph::undirected_graph::UndirectedGraph;
+use crate::graph::{Graph, GraphError, GraphMut};
+
 /// Check if a given graph is tree or not
 ///
 /// You are given an undirected graph of N nodes (numbered from 0 to N-1) and M edges. Return 1 if the graph is a tree, else return 0.
@@ -42,17 +45,16 @@ pub fn is_graph_a_tree(n: &usize, edges: &Vec<(usize, usize)>) -> Result<bool, S
         return Ok(false);
     }
 
-    // Build an adjacency list
-    let mut adjacency_list: Vec<HashSet<usize>> = vec![HashSet::new(); *n];
+    // Build an undirected graph
+    // Note: multiple edges between two vertices are only stored once
+    let mut graph: UndirectedGraph<usize> = UndirectedGraph::new();
+    for vertex in 0..*n {
+        graph.add_vertex(vertex).unwrap();
+    }
     for (from_node, to_node) in edges {
-        adjacency_list
-            .get_mut(*from_node)
-            .ok_or("Edge refer to an invalid vertex")?
-            .insert(*to_node);
-        adjacency_list
-            .get_mut(*to_node)
-            .ok_or("Edge refer to an invalid vertex")?
-            .insert(*from_node);
+        if let Err(GraphError::VertexNotFound(_)) = graph.add_edge(from_node, to_node) {
+            return Err("Edge refer to an invalid vertex".to_string());
+        }
     }
 
     // Then traverse the graph depth first
@@ -73,10 +75,10 @@ pub fn is_graph_a_tree(n: &usize, edges: &Vec<(usize, usize)>) -> Result<bool, S
         // Mark the vertex as seen
         seen.insert(current_vertex);
 
-        let current_vertex_neighbors = adjacency_list.get(current_vertex).unwrap();
+        let current_vertex_neighbors = graph.neighbors(&current_vertex).unwrap();
 
         // Add the neighbor vertices to the queue
-        for neighbor_vertex in current_vertex_neighbors {
+        for (neighbor_vertex, _) in current_vertex_neighbors {
             // If there is a self relation, the graph is not a tree
             if neighbor_vertex == &current_vertex {
                 return Ok(false);
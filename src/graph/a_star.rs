@@ -0,0 +1,329 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use super::error::GraphError;
+use super::graph_trait::{Graph, VertexId};
+use super::shortest_path::{
+    non_negative_weight_or_err, overflows_or_ok, Path, PathResult, ShortestPathTree,
+    VertexByDistance,
+};
+use super::weight::Weight;
+
+/// Return the shortest path between two vertices of the graph,
+/// or None if the target can not be reached from the source
+///
+/// This function uses [A* search algorithm](https://en.wikipedia.org/wiki/A*_search_algorithm).
+/// It works like Dijkstra's algorithm, except that the vertices are visited
+/// by order of "distance from the source + estimated distance to the target",
+/// so the vertices leading toward the target are explored first.
+///
+/// The `heuristic` estimates the distance between a vertex and the target.
+/// The returned path is the shortest one as long as the heuristic never
+/// overestimates the real distance (for example, the straight line distance
+/// on a map). If the heuristic is also consistent (the estimate never drops
+/// by more than the weight of an edge), each vertex is explored once;
+/// otherwise a vertex is explored again whenever a shorter path to it is found.
+/// Negative weights are not allowed: it Errs on the first negative edge
+/// it explores, and with `WeightOverflow` if the target can only be reached
+/// by paths whose weight does not fit in the weight type.
+pub fn a_star<G: Graph>(
+    graph: &G,
+    source_id: &VertexId<G>,
+    target_id: &VertexId<G>,
+    heuristic: impl Fn(&G::Vertex) -> G::Weight,
+) -> PathResult<G>
+where
+    G::Weight: Weight,
+{
+    search(graph, source_id, target_id, heuristic).map(|(path, _)| path)
+}
+
+/// Result of the search: the path, and the number of vertex explorations
+type SearchResult<G> =
+    Result<(Option<Path<VertexId<G>, <G as Graph>::Weight>>, usize), GraphError<VertexId<G>>>;
+
+/// Run A* search algorithm, and return the path along with the number
+/// of vertex explorations needed to find it (a vertex can be explored again
+/// when the heuristic is not consistent)
+fn search<G: Graph>(
+    graph: &G,
+    source_id: &VertexId<G>,
+    target_id: &VertexId<G>,
+    heuristic: impl Fn(&G::Vertex) -> G::Weight,
+) -> SearchResult<G>
+where
+    G::Weight: Weight,
+{
+    // Check that source and target exist, or err
+    let source = graph
+        .vertex(source_id)
+        .ok_or_else(|| GraphError::VertexNotFound(source_id.clone()))?;
+    graph.vertex_exists_or_err(target_id)?;
+
+    // Count the vertices explored in the main loop
+    let mut explored_vertices_count = 0;
+    // Keep track of the shorter distance between source and a given vertex
+    let mut shortest_distances_from_source = HashMap::new();
+    // Keep track of the shortest ways between source and a given vertex
+    // (This is stored as a linked list)
+    let mut path = HashMap::new();
+    // The priority queue is used to iterate step by step between vertices
+    // while always priorising the known vertex which seems the closest from
+    // the target, through the shortest path found from the source
+    // Note: the vertices are queued with their distance from the source,
+    // to recognize the outdated entries
+    let mut priority_queue = BinaryHeap::new();
+    // Keep track of the edges leading to paths too long for the weight type
+    let mut overflowing_edges = Vec::new();
+
+    // Initialize the state with the knowledge we have
+    // - The distance between source and itself is 0
+    // - Source is the only node we know
+    shortest_distances_from_source.insert(source_id.clone(), G::Weight::zero());
+    priority_queue.push(VertexByDistance {
+        id: (source_id.clone(), G::Weight::zero()),
+        distance: heuristic(source),
+    });
+
+    // Loop through the vertices by "estimated distance of the path going through them" order
+    while let Some(VertexByDistance {
+        id: (current_id, source_current_distance),
+        ..
+    }) = priority_queue.pop()
+    {
+        // A vertex is queued again each time a shorter path to it is found,
+        // only the entry of its shortest known path is relevant
+        if source_current_distance > shortest_distances_from_source[&current_id] {
+            continue;
+        }
+        explored_vertices_count += 1;
+
+        // If we reach the target vertex, we stop
+        if current_id == *target_id {
+            break;
+        }
+
+        // Else, for each neighbor, check if the current path is shorter
+        // than a path explored previously, and update the state
+        // Note: an explored vertex is explored again if a shorter path to it
+        // is found, which happens when the heuristic is not consistent
+        // (the estimate drops by more than the weight of an edge)
+        for (neighbor_id, current_neighbor_distance) in graph.neighbors(&current_id)? {
+            non_negative_weight_or_err(&current_id, neighbor_id, *current_neighbor_distance)?;
+
+            // distance from source->neighbor = distance from source->current + current->neighbor
+            let Some(source_neighbor_distance) =
+                source_current_distance.checked_add(*current_neighbor_distance)
+            else {
+                overflowing_edges.push((current_id.clone(), neighbor_id.clone()));
+                continue;
+            };
+
+            // An unknown distance is considered infinite
+            let is_shorter = match shortest_distances_from_source.get(neighbor_id) {
+                Some(known_distance) => source_neighbor_distance < *known_distance,
+                None => true,
+            };
+
+            if is_shorter {
+                // Update shortest distance from source, as a shorter path was found
+                shortest_distances_from_source
+                    .insert(neighbor_id.clone(), source_neighbor_distance);
+
+                // Update path with the shortest path that was found
+                path.insert(neighbor_id.clone(), current_id.clone());
+
+                // Insert the newly visited vertex in the priority queue, with
+                // its estimated distance to the target
+                // Note: the estimate only saturates if the paths through the
+                // vertex are too long for the weight type, so exploring it last is right
+                let neighbor = graph.vertex(neighbor_id).unwrap();
+                priority_queue.push(VertexByDistance {
+                    id: (neighbor_id.clone(), source_neighbor_distance),
+                    distance: source_neighbor_distance.saturating_add(heuristic(neighbor)),
+                });
+            }
+        }
+    }
+
+    // The loop ends when the target is reached or all
+    // paths were explored and none was find
+    if !shortest_distances_from_source.contains_key(target_id) {
+        overflows_or_ok(&shortest_distances_from_source, overflowing_edges)?;
+    }
+
+    let tree = ShortestPathTree {
+        source: source_id.clone(),
+        distances: shortest_distances_from_source,
+        predecessors: path,
+    };
+
+    Ok((tree.path_to(target_id), explored_vertices_count))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::graph_trait::GraphMut;
+    use super::super::shortest_path::{dijkstra, shortest_path, shortest_paths_from};
+    use super::super::test_utils::{build_weighted_graph, city, City};
+    use super::super::undirected_weighted_graph::UndirectedWeightedGraph;
+    use super::*;
+
+    /// Build a 5x5 grid of cities named after their coordinates ("x-y"),
+    /// where each city is linked to its horizontal and vertical neighbors
+    /// by a road of length 1
+    fn build_grid() -> Result<UndirectedWeightedGraph<City>, GraphError<String>> {
+        let mut graph = UndirectedWeightedGraph::new();
+
+        for x in 0..5 {
+            for y in 0..5 {
+                graph.add_vertex(city(&format!("{}-{}", x, y)))?;
+
+                if x > 0 {
+                    graph.add_edge(&format!("{}-{}", x, y), &format!("{}-{}", x - 1, y), 1)?;
+                }
+                if y > 0 {
+                    graph.add_edge(&format!("{}-{}", x, y), &format!("{}-{}", x, y - 1), 1)?;
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Coordinates of a city of the grid, from its name
+    fn coordinates(city: &City) -> (u32, u32) {
+        let (x, y) = city.name.split_once('-').unwrap();
+        (x.parse().unwrap(), y.parse().unwrap())
+    }
+
+    /// Manhattan distance to the city at the given coordinates
+    fn distance_to(x: u32, y: u32) -> impl Fn(&City) -> u32 {
+        move |city| {
+            let (city_x, city_y) = coordinates(city);
+            city_x.abs_diff(x) + city_y.abs_diff(y)
+        }
+    }
+
+    #[test]
+    fn test_a_star() -> Result<(), GraphError<String>> {
+        let graph = build_grid()?;
+
+        let path = a_star(
+            &graph,
+            &"0-0".to_string(),
+            &"4-0".to_string(),
+            distance_to(4, 0),
+        )?;
+
+        assert_eq!(
+            path,
+            Some(Path {
+                vertices: vec![
+                    "0-0".to_string(),
+                    "1-0".to_string(),
+                    "2-0".to_string(),
+                    "3-0".to_string(),
+                    "4-0".to_string(),
+                ],
+                total_weight: 4
+            })
+        );
+
+        // Unknown vertices should Err
+        assert_eq!(
+            a_star(
+                &graph,
+                &"0-0".to_string(),
+                &"Montreal".to_string(),
+                distance_to(4, 0)
+            ),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_star_without_path() -> Result<(), GraphError<String>> {
+        let mut graph = build_grid()?;
+        graph.add_vertex(city("10-10"))?;
+
+        assert_eq!(
+            a_star(
+                &graph,
+                &"0-0".to_string(),
+                &"10-10".to_string(),
+                distance_to(10, 10)
+            ),
+            Ok(None)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_star_explores_less_than_dijkstra() -> Result<(), GraphError<String>> {
+        let graph = build_grid()?;
+        let source_id = "0-0".to_string();
+        let target_id = "4-0".to_string();
+
+        // A* finds paths as short as Dijkstra's algorithm, to every city
+        let tree = shortest_paths_from(&graph, &source_id)?;
+        for city in graph.vertices() {
+            let (x, y) = coordinates(city);
+            let path = a_star(&graph, &source_id, &city.name, distance_to(x, y))?;
+            assert_eq!(
+                path.map(|path| path.total_weight),
+                tree.distance_to(&city.name)
+            );
+        }
+        assert_eq!(
+            a_star(&graph, &source_id, &target_id, distance_to(4, 0))?,
+            shortest_path(&graph, &source_id, &target_id)?
+        );
+
+        // Dijkstra explores the 10 cities closer than the target (and the target),
+        // A* only explores the cities on the way to the target
+        let (_, dijkstra_explored) =
+            dijkstra(&graph, &source_id, Some(&target_id), |_, _, weight| {
+                Some(weight)
+            })?;
+        let (_, a_star_explored) = search(&graph, &source_id, &target_id, distance_to(4, 0))?;
+        assert!(dijkstra_explored > 10);
+        assert_eq!(a_star_explored, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_inconsistent_heuristic() -> Result<(), GraphError<&'static str>> {
+        // The shortest path to T goes through A and C, but the heuristic
+        // overvalues A, so C is first explored through B
+        let graph = build_weighted_graph(
+            &["S", "A", "B", "C", "T"],
+            &[
+                ("S", "A", 1),
+                ("A", "C", 1),
+                ("S", "B", 1),
+                ("B", "C", 2),
+                ("C", "T", 3),
+            ],
+        )?;
+
+        // Never overestimates, but drops by 4 on the edge A -> C of weight 1
+        let heuristic = |city: &&str| if *city == "A" { 4 } else { 0 };
+
+        let (path, explored) = search(&graph, &"S", &"T", heuristic)?;
+        assert_eq!(
+            path,
+            Some(Path {
+                vertices: vec!["S", "A", "C", "T"],
+                total_weight: 5
+            })
+        );
+        // C is explored again once the shorter path through A is found
+        assert_eq!(explored, 6);
+
+        Ok(())
+    }
+}
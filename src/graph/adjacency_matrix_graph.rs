@@ -0,0 +1,681 @@
+use std::collections::HashMap;
+use std::iter;
+
+use super::all_pairs_shortest_paths::{self, DistanceMatrixResult};
+use super::error::GraphError;
+use super::graph_trait::{graph_neighbors, Graph, GraphMut};
+use super::identifiable::Identifiable;
+use super::oriented_graph::OrientedGraph;
+use super::oriented_weighted_graph::OrientedWeightedGraph;
+use super::weight::Weight;
+
+/// Square matrix storing the edges of an `AdjacencyMatrixGraph`
+///
+/// The cell (from, to) holds the weight of the edge between the vertices
+/// at these indices, if any. It is implemented by `BitMatrix` for
+/// unweighted graphs, and by `WeightMatrix` for weighted graphs.
+pub trait AdjacencyMatrix {
+    /// Type of the value carried by the edges
+    type Weight;
+
+    /// Instantiate an empty matrix
+    fn new() -> Self;
+
+    /// Return the weight of a cell, if any
+    fn get(&self, from: usize, to: usize) -> Option<&Self::Weight>;
+
+    /// Fill a cell, and return its previous weight, if any
+    fn insert(&mut self, from: usize, to: usize, weight: Self::Weight) -> Option<Self::Weight>;
+
+    /// Empty a cell, and return its weight, if any
+    fn remove(&mut self, from: usize, to: usize) -> Option<Self::Weight>;
+
+    /// Add an empty row and an empty column
+    fn grow(&mut self);
+
+    /// Remove a row and a column, replacing them by the last ones
+    fn swap_remove(&mut self, index: usize);
+
+    /// Iterate over the filled cells of a row, as (column, weight) pairs
+    fn row(&self, from: usize) -> impl Iterator<Item = (usize, &Self::Weight)>;
+}
+
+/// Number of bits of a word of a `BitMatrix` row
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Adjacency matrix of an unweighted graph, packing 64 cells per word
+#[derive(Debug, Clone, Default)]
+pub struct BitMatrix {
+    /// Bits of each row, the column `i` being the bit `i % 64` of the word `i / 64`
+    rows: Vec<Vec<u64>>,
+}
+
+impl BitMatrix {
+    /// Set the value of a cell, and return its previous value
+    fn set(&mut self, from: usize, to: usize, value: bool) -> bool {
+        let word = &mut self.rows[from][to / WORD_BITS];
+        let mask = 1 << (to % WORD_BITS);
+        let previous = *word & mask != 0;
+
+        match value {
+            true => *word |= mask,
+            false => *word &= !mask,
+        }
+
+        previous
+    }
+
+    /// Merge the cells of a row into another one
+    fn merge_row(&mut self, from: usize, into: usize) {
+        for word in 0..self.rows[from].len() {
+            self.rows[into][word] |= self.rows[from][word];
+        }
+    }
+}
+
+impl AdjacencyMatrix for BitMatrix {
+    type Weight = ();
+
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn get(&self, from: usize, to: usize) -> Option<&()> {
+        let word = self.rows[from][to / WORD_BITS];
+        (word >> (to % WORD_BITS) & 1 == 1).then_some(&())
+    }
+
+    fn insert(&mut self, from: usize, to: usize, _weight: ()) -> Option<()> {
+        self.set(from, to, true).then_some(())
+    }
+
+    fn remove(&mut self, from: usize, to: usize) -> Option<()> {
+        self.set(from, to, false).then_some(())
+    }
+
+    fn grow(&mut self) {
+        let word_count = (self.rows.len() + 1).div_ceil(WORD_BITS);
+
+        for row in self.rows.iter_mut() {
+            row.resize(word_count, 0);
+        }
+        self.rows.push(vec![0; word_count]);
+    }
+
+    fn swap_remove(&mut self, index: usize) {
+        let last = self.rows.len() - 1;
+
+        // Move the last column into the removed one
+        for from in 0..self.rows.len() {
+            let value = self.set(from, last, false);
+            if index != last {
+                self.set(from, index, value);
+            }
+        }
+        self.rows.swap_remove(index);
+
+        let word_count = last.div_ceil(WORD_BITS);
+        for row in self.rows.iter_mut() {
+            row.truncate(word_count);
+        }
+    }
+
+    fn row(&self, from: usize) -> impl Iterator<Item = (usize, &())> {
+        self.rows[from]
+            .iter()
+            .enumerate()
+            .flat_map(|(word_index, &word)| {
+                // Pop the lowest set bit until the word is empty
+                let mut word = word;
+                iter::from_fn(move || {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word.checked_sub(1)?;
+                    Some(word_index * WORD_BITS + bit)
+                })
+            })
+            .map(|to| (to, &()))
+    }
+}
+
+/// Adjacency matrix of a weighted graph
+#[derive(Debug, Clone)]
+pub struct WeightMatrix<W> {
+    /// Cells of each row
+    rows: Vec<Vec<Option<W>>>,
+}
+
+impl<W> AdjacencyMatrix for WeightMatrix<W> {
+    type Weight = W;
+
+    fn new() -> Self {
+        Self { rows: Vec::new() }
+    }
+
+    fn get(&self, from: usize, to: usize) -> Option<&W> {
+        self.rows[from][to].as_ref()
+    }
+
+    fn insert(&mut self, from: usize, to: usize, weight: W) -> Option<W> {
+        self.rows[from][to].replace(weight)
+    }
+
+    fn remove(&mut self, from: usize, to: usize) -> Option<W> {
+        self.rows[from][to].take()
+    }
+
+    fn grow(&mut self) {
+        for row in self.rows.iter_mut() {
+            row.push(None);
+        }
+        self.rows.push(
+            iter::repeat_with(|| None)
+                .take(self.rows.len() + 1)
+                .collect(),
+        );
+    }
+
+    fn swap_remove(&mut self, index: usize) {
+        for row in self.rows.iter_mut() {
+            row.swap_remove(index);
+        }
+        self.rows.swap_remove(index);
+    }
+
+    fn row(&self, from: usize) -> impl Iterator<Item = (usize, &W)> {
+        self.rows[from]
+            .iter()
+            .enumerate()
+            .filter_map(|(to, weight)| Some((to, weight.as_ref()?)))
+    }
+}
+
+/// A graph storing its edges in an adjacency matrix
+///
+/// The vertices are numbered with dense indices (0 to n - 1), and the
+/// matrix has a cell for each pair of vertices. Checking or updating an
+/// edge is O(1), but the matrix takes O(V^2) memory and iterating over the
+/// neighbors of a vertex is O(V), so it is best suited for dense graphs.
+/// Removing a vertex moves the last vertex to its index.
+///
+/// The matrix is a `BitMatrix` for unweighted graphs (one bit per cell),
+/// and a `WeightMatrix` for weighted graphs (see `WeightedAdjacencyMatrixGraph`).
+#[derive(Debug, Clone)]
+pub struct AdjacencyMatrixGraph<T: Identifiable, M = BitMatrix> {
+    /// Vertices, by index
+    vertices: Vec<T>,
+
+    /// Index of each vertex id
+    indices: HashMap<T::Id, usize>,
+
+    /// Edges, by (from, to) vertex indices
+    matrix: M,
+
+    /// Number of filled cells of the matrix
+    edge_count: usize,
+}
+
+/// A graph storing its weighted edges in an adjacency matrix
+///
+/// The weights are of type `W`, `u32` by default
+pub type WeightedAdjacencyMatrixGraph<T, W = u32> = AdjacencyMatrixGraph<T, WeightMatrix<W>>;
+
+impl<T: Identifiable, M: AdjacencyMatrix> AdjacencyMatrixGraph<T, M> {
+    /// Instantiate a new graph
+    pub fn new() -> Self {
+        Self {
+            vertices: Vec::new(),
+            indices: HashMap::new(),
+            matrix: M::new(),
+            edge_count: 0,
+        }
+    }
+
+    /// Build a graph with the same vertices and edges than another graph
+    pub fn from_graph<G>(graph: &G) -> Self
+    where
+        G: Graph<Vertex = T, Weight = M::Weight> + ?Sized,
+        T: Clone,
+        M::Weight: Clone,
+    {
+        let mut matrix_graph = Self::new();
+        copy_graph(graph, &mut matrix_graph);
+        matrix_graph
+    }
+
+    /// Return the index of a vertex, if it is in the graph
+    pub fn index_of(&self, id: &T::Id) -> Option<usize> {
+        self.indices.get(id).copied()
+    }
+
+    /// Return the vertex at an index
+    ///
+    /// Note: indices out of range panic, like an out of bounds vector index
+    pub fn vertex_at(&self, index: usize) -> &T {
+        &self.vertices[index]
+    }
+
+    /// Return the weight of the edge between the vertices at two indices, if any
+    ///
+    /// Note: indices out of range panic, like an out of bounds vector index
+    pub fn edge_at(&self, from: usize, to: usize) -> Option<&M::Weight> {
+        self.matrix.get(from, to)
+    }
+}
+
+impl<T: Identifiable> AdjacencyMatrixGraph<T> {
+    /// Add an edge into the graph, and Err if it already exists, or if it targets invalid vertices
+    pub fn add_edge(&mut self, from: &T::Id, to: &T::Id) -> Result<(), GraphError<T::Id>> {
+        GraphMut::add_edge(self, from, to, ())
+    }
+
+    /// Return a graph with the same vertices, and an edge A -> B
+    /// whenever B can be reached from A through one edge or more
+    ///
+    /// This function uses [Warshall's algorithm](https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm#Applications_and_generalizations):
+    /// for each vertex K, every vertex reaching K also reaches what K reaches.
+    /// Thanks to the bit-packed rows, it merges 64 cells at once.
+    pub fn transitive_closure(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut matrix = self.matrix.clone();
+
+        for step in 0..self.vertices.len() {
+            for from in 0..self.vertices.len() {
+                if from != step && matrix.get(from, step).is_some() {
+                    matrix.merge_row(step, from);
+                }
+            }
+        }
+
+        let edge_count = (0..self.vertices.len())
+            .map(|from| matrix.row(from).count())
+            .sum();
+
+        Self {
+            vertices: self.vertices.clone(),
+            indices: self.indices.clone(),
+            matrix,
+            edge_count,
+        }
+    }
+}
+
+impl<T: Identifiable, W: Weight> WeightedAdjacencyMatrixGraph<T, W> {
+    /// Return the shortest paths between every pair of vertices,
+    /// or Err if there is a negative cycle
+    ///
+    /// See `all_pairs_shortest_paths::floyd_warshall`
+    pub fn floyd_warshall(&self) -> DistanceMatrixResult<Self> {
+        all_pairs_shortest_paths::floyd_warshall(self)
+    }
+}
+
+impl<T: Identifiable, M: AdjacencyMatrix> Default for AdjacencyMatrixGraph<T, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Identifiable, M: AdjacencyMatrix> Graph for AdjacencyMatrixGraph<T, M> {
+    type Vertex = T;
+    type Weight = M::Weight;
+
+    fn vertex(&self, id: &T::Id) -> Option<&T> {
+        self.index_of(id).map(|index| &self.vertices[index])
+    }
+
+    fn vertices(&self) -> impl Iterator<Item = &T> {
+        self.vertices.iter()
+    }
+
+    fn vertex_ids(&self) -> impl Iterator<Item = &T::Id> {
+        self.vertices.iter().map(|vertex| vertex.get_id())
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        id: &T::Id,
+    ) -> Result<impl Iterator<Item = (&'a T::Id, &'a M::Weight)> + use<'a, T, M>, GraphError<T::Id>>
+    {
+        let index = self
+            .index_of(id)
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))?;
+
+        Ok(self
+            .matrix
+            .row(index)
+            .map(|(neighbor, weight)| (self.vertices[neighbor].get_id(), weight)))
+    }
+
+    fn edge(&self, from: &T::Id, to: &T::Id) -> Option<&M::Weight> {
+        self.matrix.get(self.index_of(from)?, self.index_of(to)?)
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+}
+
+impl<T: Identifiable, M: AdjacencyMatrix> GraphMut for AdjacencyMatrixGraph<T, M> {
+    /// Add a vertex into the graph, and Err if it already exists
+    fn add_vertex(&mut self, vertex: T) -> Result<(), GraphError<T::Id>> {
+        let vertex_id = vertex.get_id().clone();
+
+        // Err if vertex already exists
+        // Or insert the vertex in the graph, with a new row and column
+        if self.vertex_exists(&vertex_id) {
+            Err(GraphError::VertexAlreadyExists(vertex_id))
+        } else {
+            self.indices.insert(vertex_id, self.vertices.len());
+            self.vertices.push(vertex);
+            self.matrix.grow();
+            Ok(())
+        }
+    }
+
+    /// Add an edge into the graph, and Err if it already exists, or if it targets invalid vertices
+    fn add_edge(
+        &mut self,
+        from: &T::Id,
+        to: &T::Id,
+        weight: M::Weight,
+    ) -> Result<(), GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        let (from_index, to_index) = self.indices_or_err(from, to)?;
+
+        if self.matrix.get(from_index, to_index).is_some() {
+            return Err(GraphError::EdgeAlreadyExists {
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+
+        self.matrix.insert(from_index, to_index, weight);
+        self.edge_count += 1;
+
+        Ok(())
+    }
+
+    /// Try to remove an edge and return an Err if it was not present
+    fn remove_edge(&mut self, from: &T::Id, to: &T::Id) -> Result<(), GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        let (from_index, to_index) = self.indices_or_err(from, to)?;
+
+        match self.matrix.remove(from_index, to_index) {
+            Some(_) => {
+                self.edge_count -= 1;
+                Ok(())
+            }
+            None => Err(GraphError::EdgeNotFound {
+                from: from.clone(),
+                to: to.clone(),
+            }),
+        }
+    }
+
+    /// Try to remove a vertex and its edges, and return an Err if it was not present
+    fn remove_vertex(&mut self, id: &T::Id) -> Result<(), GraphError<T::Id>> {
+        let index = self
+            .indices
+            .remove(id)
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))?;
+
+        // Forget the edges from and to this vertex (a self loop is counted once)
+        let outgoing_count = self.matrix.row(index).count();
+        let incoming_count = (0..self.vertices.len())
+            .filter(|from| *from != index && self.matrix.get(*from, index).is_some())
+            .count();
+        self.edge_count -= outgoing_count + incoming_count;
+
+        // The last vertex takes the place of the removed one
+        self.matrix.swap_remove(index);
+        self.vertices.swap_remove(index);
+        if let Some(moved_vertex) = self.vertices.get(index) {
+            self.indices.insert(moved_vertex.get_id().clone(), index);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Identifiable, M: AdjacencyMatrix> AdjacencyMatrixGraph<T, M> {
+    /// Return the indices of two vertices, or Err if one does not exist
+    fn indices_or_err(
+        &self,
+        from: &T::Id,
+        to: &T::Id,
+    ) -> Result<(usize, usize), GraphError<T::Id>> {
+        let index_or_err = |id: &T::Id| {
+            self.index_of(id)
+                .ok_or_else(|| GraphError::VertexNotFound(id.clone()))
+        };
+
+        Ok((index_or_err(from)?, index_or_err(to)?))
+    }
+}
+
+impl<T: Identifiable + Clone> From<&OrientedGraph<T>> for AdjacencyMatrixGraph<T> {
+    fn from(graph: &OrientedGraph<T>) -> Self {
+        Self::from_graph(graph)
+    }
+}
+
+impl<T: Identifiable + Clone, W: Clone> From<&OrientedWeightedGraph<T, W>>
+    for WeightedAdjacencyMatrixGraph<T, W>
+{
+    fn from(graph: &OrientedWeightedGraph<T, W>) -> Self {
+        Self::from_graph(graph)
+    }
+}
+
+impl<T: Identifiable + Clone> From<&AdjacencyMatrixGraph<T>> for OrientedGraph<T> {
+    fn from(graph: &AdjacencyMatrixGraph<T>) -> Self {
+        let mut oriented_graph = OrientedGraph::new();
+        copy_graph(graph, &mut oriented_graph);
+        oriented_graph
+    }
+}
+
+impl<T: Identifiable + Clone, W: Clone> From<&WeightedAdjacencyMatrixGraph<T, W>>
+    for OrientedWeightedGraph<T, W>
+{
+    fn from(graph: &WeightedAdjacencyMatrixGraph<T, W>) -> Self {
+        let mut oriented_graph = OrientedWeightedGraph::new();
+        copy_graph(graph, &mut oriented_graph);
+        oriented_graph
+    }
+}
+
+/// Add the vertices and edges of a graph into an empty graph
+fn copy_graph<S, T>(source: &S, target: &mut T)
+where
+    S: Graph + ?Sized,
+    T: GraphMut<Vertex = S::Vertex, Weight = S::Weight>,
+    S::Vertex: Clone,
+    S::Weight: Clone,
+{
+    for vertex in source.vertices() {
+        target
+            .add_vertex(vertex.clone())
+            .unwrap_or_else(|_| unreachable!("the vertices come from a graph"));
+    }
+
+    for from in source.vertex_ids() {
+        for (to, weight) in graph_neighbors(source, from) {
+            target
+                .add_edge(from, to, weight.clone())
+                .unwrap_or_else(|_| unreachable!("the edges come from a graph"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::add_cities;
+    use super::*;
+
+    #[test]
+    fn test_add_and_remove() -> Result<(), GraphError<String>> {
+        let (tokio, paris, lyon) = ("Tokio".to_string(), "Paris".to_string(), "Lyon".to_string());
+        let mut graph = AdjacencyMatrixGraph::new();
+        add_cities(
+            &mut graph,
+            &["Tokio", "Paris", "Lyon"],
+            &[
+                ("Tokio", "Paris", ()),
+                ("Paris", "Lyon", ()),
+                ("Lyon", "Tokio", ()),
+                ("Paris", "Paris", ()),
+            ],
+        )?;
+
+        assert_eq!(graph.edge_count(), 4);
+        assert!(graph.edge_exists(&tokio, &paris));
+        assert!(!graph.edge_exists(&paris, &tokio));
+        assert_eq!(
+            graph.add_edge(&tokio, &paris),
+            Err(GraphError::EdgeAlreadyExists {
+                from: tokio.clone(),
+                to: paris.clone()
+            })
+        );
+        assert_eq!(
+            graph.add_edge(&tokio, &"Lyon2".to_string()),
+            Err(GraphError::VertexNotFound("Lyon2".to_string()))
+        );
+
+        assert_eq!(graph.remove_edge(&lyon, &tokio), Ok(()));
+        assert_eq!(
+            graph.remove_edge(&lyon, &tokio),
+            Err(GraphError::EdgeNotFound {
+                from: lyon.clone(),
+                to: tokio.clone()
+            })
+        );
+
+        // Lyon takes the place of Paris, and keeps its edges (its row and its column move)
+        graph.add_edge(&lyon, &tokio)?;
+        graph.add_edge(&tokio, &lyon)?;
+        graph.add_edge(&lyon, &lyon)?;
+        assert_eq!(graph.remove_vertex(&paris), Ok(()));
+        assert_eq!(graph.vertex_count(), 2);
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(graph.index_of(&lyon), Some(1));
+        assert_eq!(graph.vertex_at(1).name, "Lyon");
+        assert!(graph.edge_exists(&lyon, &tokio));
+        assert!(graph.edge_exists(&tokio, &lyon));
+        assert!(graph.edge_exists(&lyon, &lyon));
+        assert!(!graph.edge_exists(&tokio, &tokio));
+        assert_eq!(graph.edge_at(1, 0), Some(&()));
+        assert_eq!(graph.edge_at(0, 1), Some(&()));
+        assert_eq!(graph.edge_at(1, 1), Some(&()));
+        assert_eq!(graph.traverse_breadth_first(&lyon)?, vec![lyon, tokio]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_many_vertices() -> Result<(), GraphError<u32>> {
+        // A chain 0 -> 1 -> ... -> 149, spanning several words per row
+        let mut graph = AdjacencyMatrixGraph::new();
+        for vertex in 0u32..150 {
+            graph.add_vertex(vertex)?;
+        }
+        for vertex in 0u32..149 {
+            graph.add_edge(&vertex, &(vertex + 1))?;
+        }
+        graph.add_edge(&149, &64)?;
+
+        let visits: Vec<_> = graph.dfs(&0)?.map(|visit| visit.depth).collect();
+        assert_eq!(visits, (0..150).collect::<Vec<_>>());
+
+        // Removing a vertex moves the last one in its place
+        graph.remove_vertex(&3)?;
+        assert_eq!(graph.index_of(&149), Some(3));
+        assert!(graph.edge_exists(&148, &149));
+        assert!(graph.edge_exists(&149, &64));
+        assert_eq!(graph.edge_count(), 148);
+        assert_eq!(graph.traverse_breadth_first(&4)?.len(), 146);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transitive_closure() -> Result<(), GraphError<String>> {
+        let mut graph = AdjacencyMatrixGraph::new();
+        add_cities(
+            &mut graph,
+            &["a", "b", "c", "d"],
+            &[("a", "b", ()), ("b", "c", ()), ("c", "b", ())],
+        )?;
+        let closure = graph.transitive_closure();
+
+        let reachable = |from: &str| {
+            let mut ids: Vec<_> = closure
+                .neighbors(&from.to_string())
+                .unwrap()
+                .map(|(id, _)| id.as_str())
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert_eq!(reachable("a"), vec!["b", "c"]);
+        // b and c are on a cycle, so they reach themselves
+        assert_eq!(reachable("b"), vec!["b", "c"]);
+        assert_eq!(reachable("c"), vec!["b", "c"]);
+        assert!(reachable("d").is_empty());
+        assert_eq!(closure.edge_count(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conversions() -> Result<(), GraphError<&'static str>> {
+        let mut graph: OrientedWeightedGraph<&str> = OrientedWeightedGraph::new();
+        for vertex in ["a", "b", "c", "d"] {
+            graph.add_vertex(vertex)?;
+        }
+        for (from, to, weight) in [("a", "b", 4), ("a", "c", 1), ("c", "b", 2), ("b", "d", 5)] {
+            graph.add_edge(&from, &to, weight)?;
+        }
+
+        let matrix_graph = WeightedAdjacencyMatrixGraph::from(&graph);
+        assert_eq!(matrix_graph.vertex_count(), 4);
+        assert_eq!(matrix_graph.edge_count(), 4);
+        assert_eq!(matrix_graph.edge(&"c", &"b"), Some(&2));
+
+        // Same shortest paths than the original graph
+        let distances = matrix_graph.floyd_warshall()?;
+        let original_distances = graph.floyd_warshall()?;
+        for from in ["a", "b", "c", "d"] {
+            for to in ["a", "b", "c", "d"] {
+                assert_eq!(
+                    distances.distance(&from, &to),
+                    original_distances.distance(&from, &to)
+                );
+            }
+        }
+        assert_eq!(distances.distance(&"a", &"d"), Some(8));
+
+        // And back
+        let converted_graph = OrientedWeightedGraph::from(&matrix_graph);
+        assert_eq!(converted_graph.vertices, graph.vertices);
+        assert_eq!(converted_graph.edges, graph.edges);
+
+        // Unweighted graphs
+        let mut graph: OrientedGraph<&str> = OrientedGraph::new();
+        graph.add_vertex("a")?;
+        graph.add_vertex("b")?;
+        graph.add_edge(&"b", &"a")?;
+        let matrix_graph = AdjacencyMatrixGraph::from(&graph);
+        assert!(matrix_graph.edge_exists(&"b", &"a"));
+        assert_eq!(OrientedGraph::from(&matrix_graph).edges, graph.edges);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,557 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::bellman_ford;
+use super::error::GraphError;
+use super::graph_trait::{Graph, VertexId};
+use super::shortest_path::{self, Path};
+use super::weight::Weight;
+
+/// Shortest paths between every pair of vertices of a graph
+///
+/// Each vertex gets an index in the matrices: `distances[i][j]` is the
+/// shortest distance from vertex i to vertex j, and `next_hops[i][j]` is the
+/// vertex following i on the shortest path from i to j, so the shortest path
+/// between any pair of vertices can be rebuilt hop by hop.
+/// The matrices can be queried by vertex id with the methods below.
+#[derive(Debug, Clone)]
+pub struct DistanceMatrix<Id, W = u32> {
+    /// Vertex ids, by index in the matrices
+    pub vertex_ids: Vec<Id>,
+
+    /// Index in the matrices of each vertex id
+    pub indices: HashMap<Id, usize>,
+
+    /// Shortest distance between each pair of vertices
+    /// (None if the second vertex can not be reached from the first one)
+    pub distances: Vec<Vec<Option<W>>>,
+
+    /// Index of the vertex following the first vertex on the shortest path
+    /// between each pair of vertices (None if there is no path)
+    pub next_hops: Vec<Vec<Option<usize>>>,
+}
+
+impl<Id: Hash + Eq + Clone, W: Weight> DistanceMatrix<Id, W> {
+    /// Instantiate a matrix where no vertex can be reached
+    fn new(vertex_ids: Vec<Id>) -> Self {
+        let vertex_count = vertex_ids.len();
+        let indices = vertex_ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (id.clone(), index))
+            .collect();
+
+        Self {
+            vertex_ids,
+            indices,
+            distances: vec![vec![None; vertex_count]; vertex_count],
+            next_hops: vec![vec![None; vertex_count]; vertex_count],
+        }
+    }
+
+    /// Return the total cost of the shortest path between two vertices,
+    /// or None if the target can not be reached from the source
+    pub fn distance(&self, source_id: &Id, target_id: &Id) -> Option<W> {
+        let source = *self.indices.get(source_id)?;
+        let target = *self.indices.get(target_id)?;
+
+        self.distances[source][target]
+    }
+
+    /// Return the vertex following the source on the shortest path
+    /// between two vertices, or None if there is no path
+    pub fn next_hop(&self, source_id: &Id, target_id: &Id) -> Option<&Id> {
+        let source = *self.indices.get(source_id)?;
+        let target = *self.indices.get(target_id)?;
+
+        self.next_hops[source][target].map(|next| &self.vertex_ids[next])
+    }
+
+    /// Return the shortest path between two vertices,
+    /// or None if the target can not be reached from the source
+    pub fn path(&self, source_id: &Id, target_id: &Id) -> Option<Path<Id, W>> {
+        let total_weight = self.distance(source_id, target_id)?;
+        let target = self.indices[target_id];
+
+        // Build the path by following the next hops to the target
+        let mut vertices = vec![source_id.clone()];
+        let mut current = self.indices[source_id];
+        while current != target {
+            current = self.next_hops[current][target]?;
+            vertices.push(self.vertex_ids[current].clone());
+        }
+
+        Some(Path {
+            vertices,
+            total_weight,
+        })
+    }
+}
+
+/// Result of the all pairs shortest paths algorithms
+pub type DistanceMatrixResult<G> =
+    Result<DistanceMatrix<VertexId<G>, <G as Graph>::Weight>, GraphError<VertexId<G>>>;
+
+/// Return the shortest paths between every pair of vertices of the graph
+///
+/// This function uses [Floyd-Warshall algorithm](https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm).
+/// It runs in O(V^3) whatever the number of edges, so it is best suited
+/// for dense graphs. Negative weights are supported, but a cycle with
+/// a negative total weight returns a `NegativeCycle` error, and a shortest
+/// distance that does not fit in the weight type a `WeightOverflow` error.
+pub fn floyd_warshall<G: Graph>(graph: &G) -> DistanceMatrixResult<G>
+where
+    G::Weight: Weight,
+{
+    let mut matrix = initial_matrix(graph)?;
+    let vertex_count = matrix.vertex_ids.len();
+    // Keep track of an edge leading to paths too long for the weight type,
+    // for each pair of vertices
+    let mut overflowing_edges = HashMap::new();
+
+    // For each vertex, check if going through it shortens
+    // the path between every pair of vertices
+    // Note: a vertex at a negative distance of itself is in a negative cycle,
+    // which is checked before each step, and after the last one
+    let zero = Some(G::Weight::zero());
+    for step in 0..=vertex_count {
+        if let Some(vertex) =
+            (0..vertex_count).find(|&vertex| matrix.distances[vertex][vertex] < zero)
+        {
+            return Err(GraphError::NegativeCycle(negative_cycle(
+                graph, vertex, step,
+            )?));
+        }
+
+        if step < vertex_count {
+            relax_through(&mut matrix, step, &mut overflowing_edges)?;
+        }
+    }
+
+    // The overflowing paths only matter if there is no shorter path
+    for source in 0..vertex_count {
+        for target in 0..vertex_count {
+            if let (None, Some(&(from, to))) = (
+                matrix.distances[source][target],
+                overflowing_edges.get(&(source, target)),
+            ) {
+                return Err(GraphError::WeightOverflow {
+                    from: matrix.vertex_ids[from].clone(),
+                    to: matrix.vertex_ids[to].clone(),
+                });
+            }
+        }
+    }
+
+    Ok(matrix)
+}
+
+/// Return the matrix of Floyd-Warshall algorithm before any step
+fn initial_matrix<G: Graph>(graph: &G) -> DistanceMatrixResult<G>
+where
+    G::Weight: Weight,
+{
+    let mut matrix = DistanceMatrix::new(graph.vertex_ids().cloned().collect());
+
+    // Initialize the matrix with the knowledge we have
+    // - The distance between a vertex and itself is 0
+    // - The distance between two neighbors is the weight of their edge
+    for source in 0..matrix.vertex_ids.len() {
+        matrix.distances[source][source] = Some(G::Weight::zero());
+        matrix.next_hops[source][source] = Some(source);
+
+        for (neighbor_id, weight) in graph.neighbors(&matrix.vertex_ids[source])? {
+            let neighbor = matrix.indices[neighbor_id];
+
+            // A self loop is only relevant if its weight is negative
+            if is_shorter(*weight, matrix.distances[source][neighbor]) {
+                matrix.distances[source][neighbor] = Some(*weight);
+                matrix.next_hops[source][neighbor] = Some(neighbor);
+            }
+        }
+    }
+
+    Ok(matrix)
+}
+
+/// Run a step of Floyd-Warshall algorithm: shorten the path between every
+/// pair of vertices by going through the step vertex, and Err if a distance
+/// goes below the minimum of the weight type
+///
+/// When a path through the step vertex is too long for the weight type, the
+/// edge leaving the step vertex is recorded in `overflowing_edges`
+fn relax_through<Id: Hash + Eq + Clone, W: Weight>(
+    matrix: &mut DistanceMatrix<Id, W>,
+    step: usize,
+    overflowing_edges: &mut HashMap<(usize, usize), (usize, usize)>,
+) -> Result<(), GraphError<Id>> {
+    let vertex_count = matrix.vertex_ids.len();
+
+    for source in 0..vertex_count {
+        let Some(source_step_distance) = matrix.distances[source][step] else {
+            continue;
+        };
+
+        for target in 0..vertex_count {
+            let (Some(step_target_distance), Some(next_hop)) = (
+                matrix.distances[step][target],
+                matrix.next_hops[step][target],
+            ) else {
+                continue;
+            };
+
+            // distance from source->target = distance from source->step + step->target
+            let Some(source_target_distance) =
+                source_step_distance.checked_add(step_target_distance)
+            else {
+                // Only a sum of negative distances goes below the minimum
+                if step_target_distance < W::zero() {
+                    return Err(GraphError::WeightOverflow {
+                        from: matrix.vertex_ids[step].clone(),
+                        to: matrix.vertex_ids[next_hop].clone(),
+                    });
+                }
+                overflowing_edges
+                    .entry((source, target))
+                    .or_insert((step, next_hop));
+                continue;
+            };
+
+            if is_shorter(source_target_distance, matrix.distances[source][target]) {
+                matrix.distances[source][target] = Some(source_target_distance);
+                matrix.next_hops[source][target] = matrix.next_hops[source][step];
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Return the vertices of a negative cycle going through a vertex, found
+/// at a step of Floyd-Warshall algorithm
+///
+/// Once a vertex is at a negative distance of itself, the next hops may
+/// loop, so the matrix is computed again up to the previous step: the cycle
+/// is the path from the vertex to the step vertex, and back. This closed
+/// path may go several times through a vertex, it is then split into
+/// simple cycles until a negative one is found.
+fn negative_cycle<G: Graph>(
+    graph: &G,
+    vertex: usize,
+    step: usize,
+) -> Result<Vec<VertexId<G>>, GraphError<VertexId<G>>>
+where
+    G::Weight: Weight,
+{
+    let mut matrix = initial_matrix(graph)?;
+    let vertex_id = matrix.vertex_ids[vertex].clone();
+
+    // A negative self loop is found before the first step
+    let Some(last_step) = step.checked_sub(1) else {
+        return Ok(vec![vertex_id]);
+    };
+    for previous_step in 0..last_step {
+        relax_through(&mut matrix, previous_step, &mut HashMap::new())?;
+    }
+
+    let step_id = &matrix.vertex_ids[last_step];
+    let to_step = matrix.path(&vertex_id, step_id).unwrap();
+    let from_step = matrix.path(step_id, &vertex_id).unwrap();
+
+    // Walk the closed path, and cut the simple cycles it makes
+    let mut walk: Vec<VertexId<G>> = Vec::new();
+    for id in to_step
+        .vertices
+        .into_iter()
+        .chain(from_step.vertices.into_iter().skip(1))
+    {
+        if let Some(position) = walk.iter().position(|walked_id| *walked_id == id) {
+            let cycle = walk.split_off(position);
+
+            // The remaining closed path is negative if this cycle is not
+            if cycle_weight(graph, &cycle).is_some_and(|weight| weight < G::Weight::zero())
+                || walk.is_empty()
+            {
+                return Ok(cycle);
+            }
+        }
+        walk.push(id);
+    }
+
+    unreachable!("the path ends where it starts")
+}
+
+/// Return the total weight of a cycle, or None if it overflows
+fn cycle_weight<G: Graph>(graph: &G, cycle: &[VertexId<G>]) -> Option<G::Weight>
+where
+    G::Weight: Weight,
+{
+    let next_ids = cycle.iter().cycle().skip(1);
+
+    cycle
+        .iter()
+        .zip(next_ids)
+        .try_fold(G::Weight::zero(), |total_weight, (from, to)| {
+            total_weight.checked_add(*graph.edge(from, to)?)
+        })
+}
+
+/// Return the shortest paths between every pair of vertices of the graph
+///
+/// This function uses [Johnson's algorithm](https://en.wikipedia.org/wiki/Johnson%27s_algorithm).
+/// Bellman-Ford algorithm first finds a potential for each vertex, used to
+/// reweight the edges to non negative weights, then Dijkstra's algorithm
+/// is run from each vertex. It runs in O(V * E * log(V)), so it is best suited
+/// for sparse graphs. Negative weights are supported, but a cycle with
+/// a negative total weight returns a `NegativeCycle` error, and a shortest
+/// distance that does not fit in the weight type a `WeightOverflow` error.
+///
+/// Note: the reweighted distances can be longer than the real ones, so it
+/// also Errs with `WeightOverflow` when a reweighted distance does not fit.
+pub fn johnson<G: Graph>(graph: &G) -> DistanceMatrixResult<G>
+where
+    G::Weight: Weight,
+{
+    // Find the potentials, or err if there is a negative cycle or if they overflow
+    let potentials = bellman_ford::potentials(graph)?;
+
+    let mut matrix = DistanceMatrix::new(graph.vertex_ids().cloned().collect());
+
+    for source in 0..matrix.vertex_ids.len() {
+        let source_id = matrix.vertex_ids[source].clone();
+
+        // The reweighted edges u -> v weigh weight(u, v) + potential(u) - potential(v),
+        // which is never negative, so Dijkstra's algorithm can be used
+        // Note: potential(v) <= weight(u, v) + potential(u) <= weight(u, v), as the
+        // potentials are never positive, so the sum can not overflow, but the
+        // difference can (Dijkstra's algorithm then Errs if it matters)
+        let (tree, _) = shortest_path::dijkstra(graph, &source_id, None, |from, to, weight| {
+            weight
+                .checked_add(potentials[from])?
+                .checked_sub(potentials[to])
+        })?;
+
+        for (target_id, reweighted_distance) in &tree.distances {
+            let target = matrix.indices[target_id];
+
+            // The potentials of the intermediate vertices cancel each other out,
+            // only the ones of the source and the target remain in the distance
+            let distance = reweighted_distance
+                .checked_add(potentials[target_id])
+                .and_then(|distance| distance.checked_sub(potentials[&source_id]))
+                .ok_or_else(|| GraphError::WeightOverflow {
+                    from: tree.predecessor(target_id).unwrap().clone(),
+                    to: target_id.clone(),
+                })?;
+            matrix.distances[source][target] = Some(distance);
+
+            // The next hop is the vertex of the path whose predecessor is the source
+            let mut next_hop_id = target_id;
+            while let Some(predecessor_id) = tree.predecessor(next_hop_id) {
+                if *predecessor_id == source_id {
+                    break;
+                }
+                next_hop_id = predecessor_id;
+            }
+            matrix.next_hops[source][target] = Some(matrix.indices[next_hop_id]);
+        }
+    }
+
+    Ok(matrix)
+}
+
+/// Denote if a distance is shorter than a known distance
+///
+/// Note: an unknown distance is considered infinite
+fn is_shorter<W: Ord>(distance: W, known_distance: Option<W>) -> bool {
+    match known_distance {
+        Some(known_distance) => distance < known_distance,
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::graph_trait::GraphMut;
+    use super::super::test_utils::{build_weighted_graph_from_edges, rotate_cycle};
+    use super::*;
+
+    #[test]
+    fn test_floyd_warshall() -> Result<(), GraphError<&'static str>> {
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", 4),
+            ("a", "c", 2),
+            ("b", "c", -3),
+            ("c", "d", 1),
+        ])?;
+
+        let matrix = graph.floyd_warshall()?;
+
+        assert_eq!(matrix.distance(&"a", &"d"), Some(2));
+        assert_eq!(matrix.distance(&"b", &"d"), Some(-2));
+        assert_eq!(matrix.distance(&"c", &"c"), Some(0));
+        assert_eq!(matrix.next_hop(&"a", &"d"), Some(&"b"));
+        assert_eq!(
+            matrix.path(&"a", &"d"),
+            Some(Path {
+                vertices: vec!["a", "b", "c", "d"],
+                total_weight: 2
+            })
+        );
+        assert_eq!(
+            matrix.path(&"d", &"d"),
+            Some(Path {
+                vertices: vec!["d"],
+                total_weight: 0
+            })
+        );
+
+        // Unreachable and unknown vertices have no distance
+        assert_eq!(matrix.distance(&"d", &"a"), None);
+        assert_eq!(matrix.path(&"d", &"a"), None);
+        assert_eq!(matrix.distance(&"a", &"z"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_johnson_same_result_than_floyd_warshall() -> Result<(), GraphError<&'static str>> {
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", 4),
+            ("a", "c", 2),
+            ("b", "e", 3),
+            ("c", "d", 2),
+            ("c", "f", 4),
+            ("d", "e", -3),
+            ("d", "f", 1),
+            ("f", "e", 1),
+            ("e", "a", 5),
+        ])?;
+
+        let floyd_warshall_matrix = graph.floyd_warshall()?;
+        let johnson_matrix = graph.johnson()?;
+
+        for source_id in graph.vertex_ids() {
+            // Both match the single source algorithm
+            let tree = graph.bellman_ford(source_id)?;
+
+            for target_id in graph.vertex_ids() {
+                let distance = tree.distance_to(target_id);
+                assert_eq!(
+                    floyd_warshall_matrix.distance(source_id, target_id),
+                    distance
+                );
+                assert_eq!(johnson_matrix.distance(source_id, target_id), distance);
+
+                // Rebuilt paths weigh the distance
+                for matrix in [&floyd_warshall_matrix, &johnson_matrix] {
+                    let path = matrix.path(source_id, target_id).unwrap();
+                    let total_weight: i32 = path
+                        .vertices
+                        .windows(2)
+                        .map(|edge| graph.edge(&edge[0], &edge[1]).unwrap())
+                        .sum();
+                    assert_eq!(Some(total_weight), distance);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_cycle() -> Result<(), GraphError<&'static str>> {
+        // b -> c -> d -> b has a total weight of -1
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", 1),
+            ("b", "c", 2),
+            ("c", "d", -4),
+            ("d", "b", 1),
+            ("d", "e", 1),
+        ])?;
+
+        for result in [graph.floyd_warshall(), graph.johnson()] {
+            match result {
+                Err(GraphError::NegativeCycle(cycle)) => {
+                    assert_eq!(rotate_cycle(cycle, &"b"), vec!["b", "c", "d"]);
+                }
+                result => panic!("Expected a negative cycle, got {:?}", result),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_weight_overflow() -> Result<(), GraphError<&'static str>> {
+        // The distance from a to c goes below the minimum of i8
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", -100i8),
+            ("b", "c", -100),
+            ("c", "d", 100),
+            ("a", "d", -50),
+        ])?;
+        for result in [graph.floyd_warshall(), graph.johnson()] {
+            assert_eq!(
+                result.err(),
+                Some(GraphError::WeightOverflow { from: "b", to: "c" })
+            );
+        }
+
+        // With a negative cycle, the distances go below the minimum too
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", -100i8),
+            ("b", "c", -100),
+            ("c", "b", 80),
+        ])?;
+        for result in [graph.floyd_warshall(), graph.johnson()] {
+            assert!(result.is_err());
+        }
+
+        // The only path from a to c goes above the maximum of u8
+        let mut graph = build_weighted_graph_from_edges(&[("a", "b", 200u8), ("b", "c", 100)])?;
+        for result in [graph.floyd_warshall(), graph.johnson()] {
+            assert_eq!(
+                result.err(),
+                Some(GraphError::WeightOverflow { from: "b", to: "c" })
+            );
+        }
+
+        // Unless there is a shorter one
+        graph.add_edge(&"a", &"c", 250)?;
+        for matrix in [graph.floyd_warshall()?, graph.johnson()?] {
+            assert_eq!(matrix.distance(&"a", &"c"), Some(250));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_floyd_warshall_negative_cycles() -> Result<(), GraphError<&'static str>> {
+        // A negative self loop
+        let graph = build_weighted_graph_from_edges(&[("a", "b", 1), ("b", "b", -1)])?;
+        assert_eq!(
+            graph.floyd_warshall().err(),
+            Some(GraphError::NegativeCycle(vec!["b"]))
+        );
+
+        // The path from a to c and back goes through a zero cycle: the
+        // negative cycle is c -> d -> c
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", 1),
+            ("b", "a", -1),
+            ("b", "c", 1),
+            ("c", "d", 1),
+            ("d", "c", -3),
+            ("c", "b", 1),
+        ])?;
+        match graph.floyd_warshall() {
+            Err(GraphError::NegativeCycle(cycle)) => {
+                assert_eq!(rotate_cycle(cycle, &"c"), vec!["c", "d"]);
+            }
+            result => panic!("Expected a negative cycle, got {:?}", result),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,453 @@
+use std::fmt;
+
+use super::error::GraphError;
+
+/// Handle to a vertex of an `ArenaGraph`
+///
+/// It is only valid for the graph that returned it, until the vertex is removed.
+/// The slot of a removed vertex can be reused, but with a new generation,
+/// so a stale handle never refers to the new vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VertexIndex {
+    index: usize,
+    generation: u32,
+}
+
+/// Handle to an edge of an `ArenaGraph`
+///
+/// It is only valid for the graph that returned it, until the edge is removed
+/// (removing a vertex removes its edges).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeIndex {
+    index: usize,
+    generation: u32,
+}
+
+impl fmt::Display for VertexIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} (generation {})", self.index, self.generation)
+    }
+}
+
+impl fmt::Display for EdgeIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} (generation {})", self.index, self.generation)
+    }
+}
+
+/// An oriented graph storing its vertices and edges in arenas
+///
+/// Unlike `OrientedGraph`, which refers to the vertices by cloned ids and
+/// has to clean every reference by hand, the vertices and edges live in
+/// vectors and are referred to by `VertexIndex` and `EdgeIndex` handles.
+/// Each slot of the vectors has a generation, increased when its value is
+/// removed, and checked against the generation of the handles, so stale
+/// handles are detected instead of silently pointing to another value.
+///
+/// Vertices carry a payload of type `V` and edges a payload of type `E`.
+/// Several edges can link the same vertices, as they are told apart by their handle.
+#[derive(Debug, Clone)]
+pub struct ArenaGraph<V, E = ()> {
+    /// Vertex slots, the free ones being reused by `add_vertex`
+    vertices: Vec<Slot<VertexEntry<V>>>,
+
+    /// Edge slots, the free ones being reused by `add_edge`
+    edges: Vec<Slot<EdgeEntry<E>>>,
+
+    /// Indices of the free vertex slots
+    free_vertices: Vec<usize>,
+
+    /// Indices of the free edge slots
+    free_edges: Vec<usize>,
+}
+
+/// A slot of an arena, empty once its value is removed
+#[derive(Debug, Clone)]
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+}
+
+#[derive(Debug, Clone)]
+struct VertexEntry<V> {
+    payload: V,
+
+    /// Slot indices of the edges going out of the vertex
+    outgoing: Vec<usize>,
+
+    /// Slot indices of the edges coming into the vertex
+    incoming: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct EdgeEntry<E> {
+    payload: E,
+    from: VertexIndex,
+    to: VertexIndex,
+}
+
+impl<V, E> ArenaGraph<V, E> {
+    /// Instantiate a new graph
+    pub fn new() -> Self {
+        Self {
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            free_vertices: Vec::new(),
+            free_edges: Vec::new(),
+        }
+    }
+
+    /// Return the number of vertices in the graph
+    pub fn vertex_count(&self) -> usize {
+        self.vertices.len() - self.free_vertices.len()
+    }
+
+    /// Return the number of edges in the graph
+    pub fn edge_count(&self) -> usize {
+        self.edges.len() - self.free_edges.len()
+    }
+
+    /// Add a vertex into the graph, and return its handle
+    pub fn add_vertex(&mut self, payload: V) -> VertexIndex {
+        let entry = VertexEntry {
+            payload,
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        };
+        let (index, generation) = insert(&mut self.vertices, &mut self.free_vertices, entry);
+
+        VertexIndex { index, generation }
+    }
+
+    /// Add an edge into the graph, and return its handle,
+    /// or Err if a vertex handle is stale
+    pub fn add_edge(
+        &mut self,
+        from: VertexIndex,
+        to: VertexIndex,
+        payload: E,
+    ) -> Result<EdgeIndex, GraphError<VertexIndex>> {
+        // Check that vertices exist, or err
+        for vertex in [from, to] {
+            if !self.contains_vertex(vertex) {
+                return Err(GraphError::VertexNotFound(vertex));
+            }
+        }
+
+        let entry = EdgeEntry { payload, from, to };
+        let (index, generation) = insert(&mut self.edges, &mut self.free_edges, entry);
+
+        self.vertex_entry_mut(from).unwrap().outgoing.push(index);
+        self.vertex_entry_mut(to).unwrap().incoming.push(index);
+
+        Ok(EdgeIndex { index, generation })
+    }
+
+    /// Remove a vertex and its edges, and return its payload,
+    /// or None if the handle is stale
+    pub fn remove_vertex(&mut self, vertex: VertexIndex) -> Option<V> {
+        self.vertex_entry(vertex)?;
+
+        let entry = remove(&mut self.vertices, &mut self.free_vertices, vertex.index);
+
+        // Remove the edges from and to this vertex
+        // (a self loop is in both lists, but is only removed once)
+        for edge_index in entry.outgoing.iter().chain(&entry.incoming) {
+            if let Some(edge) = self.edges[*edge_index].value.as_ref() {
+                let (from, to) = (edge.from, edge.to);
+                remove(&mut self.edges, &mut self.free_edges, *edge_index);
+                self.unlink_edge(from, to, *edge_index);
+            }
+        }
+
+        Some(entry.payload)
+    }
+
+    /// Remove an edge and return its payload, or None if the handle is stale
+    pub fn remove_edge(&mut self, edge: EdgeIndex) -> Option<E> {
+        self.edge_entry(edge)?;
+
+        let entry = remove(&mut self.edges, &mut self.free_edges, edge.index);
+        self.unlink_edge(entry.from, entry.to, edge.index);
+
+        Some(entry.payload)
+    }
+
+    /// Denote if a vertex handle is valid
+    pub fn contains_vertex(&self, vertex: VertexIndex) -> bool {
+        self.vertex_entry(vertex).is_some()
+    }
+
+    /// Denote if an edge handle is valid
+    pub fn contains_edge(&self, edge: EdgeIndex) -> bool {
+        self.edge_entry(edge).is_some()
+    }
+
+    /// Return the payload of a vertex, or None if the handle is stale
+    pub fn vertex(&self, vertex: VertexIndex) -> Option<&V> {
+        self.vertex_entry(vertex).map(|entry| &entry.payload)
+    }
+
+    /// Return the payload of a vertex to modify it, or None if the handle is stale
+    pub fn vertex_mut(&mut self, vertex: VertexIndex) -> Option<&mut V> {
+        self.vertex_entry_mut(vertex)
+            .map(|entry| &mut entry.payload)
+    }
+
+    /// Return the payload of an edge, or None if the handle is stale
+    pub fn edge(&self, edge: EdgeIndex) -> Option<&E> {
+        self.edge_entry(edge).map(|entry| &entry.payload)
+    }
+
+    /// Return the payload of an edge to modify it, or None if the handle is stale
+    pub fn edge_mut(&mut self, edge: EdgeIndex) -> Option<&mut E> {
+        get_mut(&mut self.edges, edge.index, edge.generation).map(|entry| &mut entry.payload)
+    }
+
+    /// Return the (from, to) vertices of an edge, or None if the handle is stale
+    pub fn edge_endpoints(&self, edge: EdgeIndex) -> Option<(VertexIndex, VertexIndex)> {
+        self.edge_entry(edge).map(|entry| (entry.from, entry.to))
+    }
+
+    /// Return an edge going from a vertex to another one, if any
+    pub fn find_edge(&self, from: VertexIndex, to: VertexIndex) -> Option<EdgeIndex> {
+        self.outgoing_edges(from)?
+            .find(|(_, neighbor)| *neighbor == to)
+            .map(|(edge, _)| edge)
+    }
+
+    /// Iterate over the vertices, as (handle, payload) pairs
+    pub fn vertices(&self) -> impl Iterator<Item = (VertexIndex, &V)> {
+        occupied(&self.vertices)
+            .map(|(index, generation, entry)| (VertexIndex { index, generation }, &entry.payload))
+    }
+
+    /// Iterate over the edges, as (handle, payload) pairs
+    pub fn edges(&self) -> impl Iterator<Item = (EdgeIndex, &E)> {
+        occupied(&self.edges)
+            .map(|(index, generation, entry)| (EdgeIndex { index, generation }, &entry.payload))
+    }
+
+    /// Iterate over the edges going out of a vertex, as (edge, target vertex) pairs,
+    /// or None if the handle is stale
+    pub fn outgoing_edges(
+        &self,
+        vertex: VertexIndex,
+    ) -> Option<impl Iterator<Item = (EdgeIndex, VertexIndex)> + '_> {
+        let entry = self.vertex_entry(vertex)?;
+
+        Some(entry.outgoing.iter().map(|index| {
+            let edge = self.edge_handle(*index);
+            (edge, self.edge_entry(edge).unwrap().to)
+        }))
+    }
+
+    /// Iterate over the edges coming into a vertex, as (edge, source vertex) pairs,
+    /// or None if the handle is stale
+    pub fn incoming_edges(
+        &self,
+        vertex: VertexIndex,
+    ) -> Option<impl Iterator<Item = (EdgeIndex, VertexIndex)> + '_> {
+        let entry = self.vertex_entry(vertex)?;
+
+        Some(entry.incoming.iter().map(|index| {
+            let edge = self.edge_handle(*index);
+            (edge, self.edge_entry(edge).unwrap().from)
+        }))
+    }
+
+    fn vertex_entry(&self, vertex: VertexIndex) -> Option<&VertexEntry<V>> {
+        get(&self.vertices, vertex.index, vertex.generation)
+    }
+
+    fn vertex_entry_mut(&mut self, vertex: VertexIndex) -> Option<&mut VertexEntry<V>> {
+        get_mut(&mut self.vertices, vertex.index, vertex.generation)
+    }
+
+    fn edge_entry(&self, edge: EdgeIndex) -> Option<&EdgeEntry<E>> {
+        get(&self.edges, edge.index, edge.generation)
+    }
+
+    /// Return the handle of an occupied edge slot
+    fn edge_handle(&self, index: usize) -> EdgeIndex {
+        EdgeIndex {
+            index,
+            generation: self.edges[index].generation,
+        }
+    }
+
+    /// Remove a removed edge from the lists of its vertices (if they still exist)
+    fn unlink_edge(&mut self, from: VertexIndex, to: VertexIndex, edge_index: usize) {
+        if let Some(entry) = self.vertex_entry_mut(from) {
+            entry.outgoing.retain(|index| *index != edge_index);
+        }
+        if let Some(entry) = self.vertex_entry_mut(to) {
+            entry.incoming.retain(|index| *index != edge_index);
+        }
+    }
+}
+
+impl<V, E> Default for ArenaGraph<V, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Store a value in a free slot of an arena, or in a new one,
+/// and return its (index, generation)
+fn insert<T>(slots: &mut Vec<Slot<T>>, free_slots: &mut Vec<usize>, value: T) -> (usize, u32) {
+    match free_slots.pop() {
+        Some(index) => {
+            slots[index].value = Some(value);
+            (index, slots[index].generation)
+        }
+        None => {
+            slots.push(Slot {
+                generation: 0,
+                value: Some(value),
+            });
+            (slots.len() - 1, 0)
+        }
+    }
+}
+
+/// Take the value of an occupied slot, and invalidate its handles
+fn remove<T>(slots: &mut [Slot<T>], free_slots: &mut Vec<usize>, index: usize) -> T {
+    let slot = &mut slots[index];
+
+    slot.generation = slot.generation.wrapping_add(1);
+    free_slots.push(index);
+
+    slot.value.take().unwrap()
+}
+
+/// Return the value of a slot, if the generation matches
+fn get<T>(slots: &[Slot<T>], index: usize, generation: u32) -> Option<&T> {
+    slots
+        .get(index)
+        .filter(|slot| slot.generation == generation)
+        .and_then(|slot| slot.value.as_ref())
+}
+
+/// Return the value of a slot to modify it, if the generation matches
+fn get_mut<T>(slots: &mut [Slot<T>], index: usize, generation: u32) -> Option<&mut T> {
+    slots
+        .get_mut(index)
+        .filter(|slot| slot.generation == generation)
+        .and_then(|slot| slot.value.as_mut())
+}
+
+/// Iterate over the occupied slots, as (index, generation, value)
+fn occupied<T>(slots: &[Slot<T>]) -> impl Iterator<Item = (usize, u32, &T)> {
+    slots
+        .iter()
+        .enumerate()
+        .filter_map(|(index, slot)| Some((index, slot.generation, slot.value.as_ref()?)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::{city, City};
+    use super::*;
+
+    #[test]
+    fn test_add() -> Result<(), GraphError<VertexIndex>> {
+        let mut graph: ArenaGraph<City, u32> = ArenaGraph::new();
+
+        let tokio = graph.add_vertex(city("Tokio"));
+        let paris = graph.add_vertex(city("Paris"));
+        let flight = graph.add_edge(tokio, paris, 13)?;
+
+        assert_eq!(graph.vertex_count(), 2);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(graph.vertex(paris), Some(&city("Paris")));
+        assert_eq!(graph.edge(flight), Some(&13));
+        assert_eq!(graph.edge_endpoints(flight), Some((tokio, paris)));
+        assert_eq!(graph.find_edge(tokio, paris), Some(flight));
+        assert_eq!(graph.find_edge(paris, tokio), None);
+
+        // Several edges can link the same vertices
+        let other_flight = graph.add_edge(tokio, paris, 15)?;
+        assert_ne!(flight, other_flight);
+        assert_eq!(graph.outgoing_edges(tokio).unwrap().count(), 2);
+        assert_eq!(
+            graph.incoming_edges(paris).unwrap().collect::<Vec<_>>(),
+            vec![(flight, tokio), (other_flight, tokio)]
+        );
+
+        // Payloads can be modified in place
+        *graph.edge_mut(other_flight).unwrap() += 1;
+        graph.vertex_mut(tokio).unwrap().name = "Tokyo".to_string();
+        assert_eq!(graph.edge(other_flight), Some(&16));
+        assert_eq!(graph.vertex(tokio), Some(&city("Tokyo")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_vertex() -> Result<(), GraphError<VertexIndex>> {
+        let mut graph: ArenaGraph<City, u32> = ArenaGraph::new();
+
+        let tokio = graph.add_vertex(city("Tokio"));
+        let paris = graph.add_vertex(city("Paris"));
+        let vancouver = graph.add_vertex(city("Vancouver"));
+        let to_paris = graph.add_edge(tokio, paris, 13)?;
+        let from_paris = graph.add_edge(paris, vancouver, 10)?;
+        let loop_paris = graph.add_edge(paris, paris, 1)?;
+        let to_vancouver = graph.add_edge(tokio, vancouver, 9)?;
+
+        // Should remove the vertex and its edges, in both directions
+        assert_eq!(graph.remove_vertex(paris), Some(city("Paris")));
+        assert_eq!(graph.vertex_count(), 2);
+        assert_eq!(graph.edge_count(), 1);
+        for edge in [to_paris, from_paris, loop_paris] {
+            assert!(!graph.contains_edge(edge));
+        }
+        assert_eq!(
+            graph.outgoing_edges(tokio).unwrap().collect::<Vec<_>>(),
+            vec![(to_vancouver, vancouver)]
+        );
+        assert_eq!(graph.incoming_edges(vancouver).unwrap().count(), 1);
+
+        // The handle is stale
+        assert_eq!(graph.remove_vertex(paris), None);
+        assert_eq!(graph.vertex(paris), None);
+        assert!(graph.outgoing_edges(paris).is_none());
+        assert_eq!(
+            graph.add_edge(tokio, paris, 1),
+            Err(GraphError::VertexNotFound(paris))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_handles_after_reuse() -> Result<(), GraphError<VertexIndex>> {
+        let mut graph: ArenaGraph<City, u32> = ArenaGraph::new();
+
+        let tokio = graph.add_vertex(city("Tokio"));
+        let paris = graph.add_vertex(city("Paris"));
+        let flight = graph.add_edge(tokio, paris, 13)?;
+
+        graph.remove_vertex(paris);
+        graph.remove_edge(flight);
+
+        // The slots are reused, with new generations
+        let lyon = graph.add_vertex(city("Lyon"));
+        let train = graph.add_edge(tokio, lyon, 2)?;
+        assert_eq!(graph.vertices().count(), 2);
+        assert_eq!(graph.vertex(paris), None);
+        assert_eq!(graph.vertex(lyon), Some(&city("Lyon")));
+        assert_eq!(graph.edge(flight), None);
+        assert_eq!(graph.edge_mut(flight), None);
+        assert_eq!(graph.edge(train), Some(&2));
+
+        // Removing an edge with a stale handle does nothing
+        assert_eq!(graph.remove_edge(flight), None);
+        assert_eq!(graph.remove_edge(train), Some(2));
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(graph.edges().count(), 0);
+
+        Ok(())
+    }
+}
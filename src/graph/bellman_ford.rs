@@ -0,0 +1,324 @@
+use std::collections::HashMap;
+
+use super::error::GraphError;
+use super::graph_trait::{Graph, VertexId};
+use super::shortest_path::{overflows_or_ok, ShortestPathTree, ShortestPathsResult};
+use super::weight::Weight;
+
+/// Return the shortest paths from a source vertex to every vertex it can reach
+///
+/// This function uses [Bellman-Ford algorithm](https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm).
+/// It is slower than Dijkstra's algorithm (O(V * E) instead of O(E * log(V))),
+/// but supports negative weights.
+///
+/// If a cycle with a negative total weight can be reached from the source,
+/// paths can be made infinitely short, so it returns a `NegativeCycle` error
+/// carrying the vertices of that cycle. If a path goes below the minimum of
+/// the weight type, or if a vertex can only be reached by paths going above
+/// its maximum, it returns a `WeightOverflow` error.
+pub fn bellman_ford<G: Graph>(graph: &G, source_id: &VertexId<G>) -> ShortestPathsResult<G>
+where
+    G::Weight: Weight,
+{
+    // Check that source exists, or err
+    graph.vertex_exists_or_err(source_id)?;
+
+    let mut distances = HashMap::new();
+    let mut predecessors = HashMap::new();
+
+    // The distance between source and itself is 0
+    distances.insert(source_id.clone(), G::Weight::zero());
+
+    // A shortest path has at most |V| - 1 edges, so relaxing all the edges
+    // |V| - 1 times is enough to find all the shortest paths
+    relax_until_stable(
+        graph,
+        &mut distances,
+        &mut predecessors,
+        graph.vertex_count().saturating_sub(1),
+    )?;
+
+    Ok(ShortestPathTree {
+        source: source_id.clone(),
+        distances,
+        predecessors,
+    })
+}
+
+/// Result of the potentials computation: a potential by vertex id
+type PotentialsResult<G> =
+    Result<HashMap<VertexId<G>, <G as Graph>::Weight>, GraphError<VertexId<G>>>;
+
+/// Return a potential for each vertex of the graph, such that for any edge
+/// u -> v: potential(u) + weight(u, v) >= potential(v)
+///
+/// The potentials are the distances from a virtual vertex linked to every
+/// vertex by an edge of weight 0, which are used by Johnson's algorithm to
+/// reweight the edges to non negative weights. It returns a `NegativeCycle`
+/// error if the graph contains a cycle with a negative total weight, and a
+/// `WeightOverflow` error if a potential does not fit in the weight type.
+pub(crate) fn potentials<G: Graph>(graph: &G) -> PotentialsResult<G>
+where
+    G::Weight: Weight,
+{
+    // Every vertex is at distance 0 of the virtual vertex
+    let mut distances: HashMap<_, _> = graph
+        .vertex_ids()
+        .map(|vertex_id| (vertex_id.clone(), G::Weight::zero()))
+        .collect();
+    let mut predecessors = HashMap::new();
+
+    // With the virtual vertex, the graph has |V| + 1 vertices,
+    // so |V| passes are needed
+    relax_until_stable(
+        graph,
+        &mut distances,
+        &mut predecessors,
+        graph.vertex_count(),
+    )?;
+
+    Ok(distances)
+}
+
+/// Relax the edges of the graph until the distances stop changing,
+/// in at most `max_passes` passes, and Err if a negative cycle is found
+/// or if a distance overflows
+fn relax_until_stable<G: Graph>(
+    graph: &G,
+    distances: &mut HashMap<VertexId<G>, G::Weight>,
+    predecessors: &mut HashMap<VertexId<G>, VertexId<G>>,
+    max_passes: usize,
+) -> Result<(), GraphError<VertexId<G>>>
+where
+    G::Weight: Weight,
+{
+    // Edges whose target could only be reached with a distance above the
+    // maximum of the weight type, as (from, to) pairs
+    let mut overflowing_edges = Vec::new();
+
+    // Note: the loop can stop early when a pass does not update anything
+    for _ in 0..max_passes {
+        if relax_edges(graph, distances, predecessors, &mut overflowing_edges)?.is_none() {
+            return overflows_or_ok(distances, overflowing_edges);
+        }
+    }
+
+    // If a distance can still be updated, there is a negative cycle
+    if let Some(updated_id) = relax_edges(graph, distances, predecessors, &mut overflowing_edges)? {
+        let cycle = find_cycle(predecessors, updated_id, graph.vertex_count());
+        return Err(GraphError::NegativeCycle(cycle));
+    }
+
+    overflows_or_ok(distances, overflowing_edges)
+}
+
+/// Relax every edge of the graph once, and return the last vertex whose
+/// distance from the source was updated (None if nothing changed)
+///
+/// It Errs if a distance goes below the minimum of the weight type, and
+/// records the edges leading above its maximum in `overflowing_edges`:
+/// they only matter if their target is not reached by a shorter path.
+fn relax_edges<G: Graph>(
+    graph: &G,
+    distances: &mut HashMap<VertexId<G>, G::Weight>,
+    predecessors: &mut HashMap<VertexId<G>, VertexId<G>>,
+    overflowing_edges: &mut Vec<(VertexId<G>, VertexId<G>)>,
+) -> Result<Option<VertexId<G>>, GraphError<VertexId<G>>>
+where
+    G::Weight: Weight,
+{
+    let mut updated_id = None;
+
+    for vertex_id in graph.vertex_ids() {
+        // Edges from vertices not reached yet can not be relaxed
+        let Some(&source_vertex_distance) = distances.get(vertex_id) else {
+            continue;
+        };
+
+        for (neighbor_id, weight) in graph.neighbors(vertex_id)? {
+            // distance from source->neighbor = distance from source->vertex + vertex->neighbor
+            let Some(source_neighbor_distance) = source_vertex_distance.checked_add(*weight) else {
+                let error = GraphError::WeightOverflow {
+                    from: vertex_id.clone(),
+                    to: neighbor_id.clone(),
+                };
+                match *weight < G::Weight::zero() {
+                    true => return Err(error),
+                    false => {
+                        overflowing_edges.push((vertex_id.clone(), neighbor_id.clone()));
+                        continue;
+                    }
+                }
+            };
+
+            // An unknown distance is considered infinite
+            let is_shorter = match distances.get(neighbor_id) {
+                Some(known_distance) => source_neighbor_distance < *known_distance,
+                None => true,
+            };
+
+            if is_shorter {
+                distances.insert(neighbor_id.clone(), source_neighbor_distance);
+                predecessors.insert(neighbor_id.clone(), vertex_id.clone());
+                updated_id = Some(neighbor_id.clone());
+            }
+        }
+    }
+
+    Ok(updated_id)
+}
+
+/// Return the vertices of the negative cycle found from a vertex updated
+/// during the last relaxation pass, in the order of the cycle edges
+fn find_cycle<Id: std::hash::Hash + Eq + Clone>(
+    predecessors: &HashMap<Id, Id>,
+    updated_id: Id,
+    vertex_count: usize,
+) -> Vec<Id> {
+    // The updated vertex is reachable from the cycle, but is not
+    // necessarily in it: going back |V| times through the predecessors
+    // guarantees to land in the cycle
+    let mut cycle_vertex_id = updated_id;
+    for _ in 0..vertex_count {
+        cycle_vertex_id = predecessors[&cycle_vertex_id].clone();
+    }
+
+    // Then walk the cycle backward until coming back to the first vertex
+    let mut cycle = vec![cycle_vertex_id.clone()];
+    let mut current_id = &predecessors[&cycle_vertex_id];
+    while *current_id != cycle_vertex_id {
+        cycle.push(current_id.clone());
+        current_id = &predecessors[current_id];
+    }
+
+    // Reverse the cycle, as it first come backward
+    cycle.reverse();
+
+    cycle
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::{build_weighted_graph_from_edges, rotate_cycle};
+    use super::*;
+
+    #[test]
+    fn test_negative_weights() -> Result<(), GraphError<&'static str>> {
+        // Going through b is longer, but the credit on b -> c makes it the shortest path
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", 4),
+            ("a", "c", 2),
+            ("b", "c", -3),
+            ("c", "d", 1),
+        ])?;
+
+        let tree = graph.bellman_ford(&"a")?;
+
+        assert_eq!(tree.distance_to(&"c"), Some(1));
+        assert_eq!(tree.distance_to(&"d"), Some(2));
+        assert_eq!(
+            tree.path_to(&"d").map(|path| path.vertices),
+            Some(vec!["a", "b", "c", "d"])
+        );
+
+        // Unreachable vertices have no distance
+        assert_eq!(graph.bellman_ford(&"c")?.distance_to(&"a"), None);
+
+        // Unknown source should Err
+        assert_eq!(
+            graph.bellman_ford(&"z").err(),
+            Some(GraphError::VertexNotFound("z"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_same_result_than_dijkstra_with_positive_weights() -> Result<(), GraphError<&'static str>>
+    {
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", 4),
+            ("a", "c", 2),
+            ("b", "e", 3),
+            ("c", "d", 2),
+            ("c", "f", 4),
+            ("d", "e", 3),
+            ("d", "f", 1),
+            ("f", "e", 1),
+        ])?;
+
+        let bellman_ford_tree = graph.bellman_ford(&"a")?;
+        let dijkstra_tree = graph.shortest_paths_from(&"a")?;
+
+        assert_eq!(bellman_ford_tree.distances, dijkstra_tree.distances);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_cycle() -> Result<(), GraphError<&'static str>> {
+        // b -> c -> d -> b has a total weight of -1
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", 1),
+            ("b", "c", 2),
+            ("c", "d", -4),
+            ("d", "b", 1),
+            ("d", "e", 1),
+        ])?;
+
+        match graph.bellman_ford(&"a") {
+            Err(GraphError::NegativeCycle(cycle)) => {
+                assert_eq!(rotate_cycle(cycle, &"b"), vec!["b", "c", "d"]);
+            }
+            result => panic!("Expected a negative cycle, got {:?}", result),
+        }
+
+        // The cycle is ignored when it can not be reached from the source
+        assert_eq!(graph.bellman_ford(&"e")?.distances.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_weight_overflow() -> Result<(), GraphError<&'static str>> {
+        // a -> b -> c weighs -200, below i8::MIN, without any cycle
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", -100i8),
+            ("b", "c", -100),
+            ("c", "d", 10),
+        ])?;
+        assert_eq!(
+            graph.bellman_ford(&"a").err(),
+            Some(GraphError::WeightOverflow { from: "b", to: "c" })
+        );
+
+        // The cycle b -> c -> b weighs -20, but the distances overflow first
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", -100i8),
+            ("b", "c", -100),
+            ("c", "b", 80),
+        ])?;
+        assert_eq!(
+            graph.bellman_ford(&"a").err(),
+            Some(GraphError::WeightOverflow { from: "b", to: "c" })
+        );
+
+        // The path to c goes above u8::MAX, d is reached through c
+        let graph =
+            build_weighted_graph_from_edges(&[("a", "b", 200u8), ("b", "c", 100), ("c", "d", 1)])?;
+        assert_eq!(
+            graph.bellman_ford(&"a").err(),
+            Some(GraphError::WeightOverflow { from: "b", to: "c" })
+        );
+
+        // An overflowing path does not matter if a shorter one exists
+        let graph = build_weighted_graph_from_edges(&[
+            ("a", "b", 200u8),
+            ("b", "c", 100),
+            ("a", "c", 250),
+        ])?;
+        assert_eq!(graph.bellman_ford(&"a")?.distance_to(&"c"), Some(250));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use super::graph_trait::{undirected_neighbors, Graph, VertexId};
+
+/// Weak points of a graph, ignoring the orientation of the edges
+///
+/// - An articulation point is a vertex whose removal disconnects its component
+/// - A bridge is an edge whose removal disconnects its component
+/// - A biconnected component is a maximal set of vertices that stays
+///   connected whatever single vertex is removed
+///
+/// Note: two edges A -> B and B -> A are considered as a single link A - B,
+/// and self loops are ignored
+#[derive(Debug, Clone)]
+pub struct Biconnectivity<Id> {
+    /// Vertex ids of the articulation points
+    pub articulation_points: Vec<Id>,
+
+    /// Bridges, oriented along an edge of the graph
+    pub bridges: Vec<(Id, Id)>,
+
+    /// Vertex ids of each biconnected component
+    ///
+    /// Note: the articulation points belong to several components,
+    /// and isolated vertices belong to none
+    pub components: Vec<Vec<Id>>,
+}
+
+impl<Id: PartialEq> Biconnectivity<Id> {
+    /// Denote if a vertex is an articulation point
+    pub fn is_articulation_point(&self, id: &Id) -> bool {
+        self.articulation_points.contains(id)
+    }
+
+    /// Denote if the link between two vertices is a bridge, whatever its orientation
+    pub fn is_bridge(&self, id1: &Id, id2: &Id) -> bool {
+        self.bridges
+            .iter()
+            .any(|(from, to)| (from, to) == (id1, id2) || (from, to) == (id2, id1))
+    }
+}
+
+/// Return the articulation points, bridges and biconnected components of the graph
+///
+/// This function uses [Hopcroft and Tarjan's algorithm](https://en.wikipedia.org/wiki/Biconnected_component#Algorithms).
+/// It explores the graph depth first, and computes for each vertex the
+/// lowest discovery index reachable from its subtree with a single back edge.
+/// A child subtree that can not reach above its parent is cut from the rest
+/// of the graph by the parent, and its edges (kept on a stack) form a component.
+pub fn biconnectivity<G: Graph + ?Sized>(graph: &G) -> Biconnectivity<VertexId<G>> {
+    let neighbors = undirected_neighbors(graph);
+
+    let mut indices: HashMap<&VertexId<G>, usize> = HashMap::new();
+    let mut low_links: HashMap<&VertexId<G>, usize> = HashMap::new();
+    let mut articulation_points = HashSet::new();
+    let mut bridges = Vec::new();
+    let mut components = Vec::new();
+
+    // Explored edges not assigned to a component yet
+    let mut edge_stack = Vec::new();
+
+    for root_id in graph.vertex_ids() {
+        if indices.contains_key(root_id) {
+            continue;
+        }
+
+        indices.insert(root_id, indices.len());
+        low_links.insert(root_id, indices[root_id]);
+        let mut root_children = 0;
+
+        // Path from the root to the current vertex, with the parent
+        // and the neighbors left to explore of each vertex
+        let mut stack = vec![(root_id, None, neighbors[root_id].iter())];
+
+        while let Some((vertex_id, parent_id, vertex_neighbors)) = stack.last_mut() {
+            let (vertex_id, parent_id) = (*vertex_id, *parent_id);
+
+            match vertex_neighbors.next() {
+                Some(&neighbor_id) => {
+                    // Self loops and the link to the parent are not back edges
+                    if neighbor_id == vertex_id || Some(neighbor_id) == parent_id {
+                        continue;
+                    }
+
+                    match indices.get(neighbor_id) {
+                        // Not discovered yet: go deeper
+                        None => {
+                            indices.insert(neighbor_id, indices.len());
+                            low_links.insert(neighbor_id, indices[neighbor_id]);
+                            edge_stack.push((vertex_id, neighbor_id));
+                            stack.push((
+                                neighbor_id,
+                                Some(vertex_id),
+                                neighbors[neighbor_id].iter(),
+                            ));
+                        }
+                        // Back edge to an ancestor
+                        // (an edge to a descendant was already seen from the descendant)
+                        Some(&neighbor_index) if neighbor_index < indices[vertex_id] => {
+                            edge_stack.push((vertex_id, neighbor_id));
+                            let low_link = low_links[vertex_id].min(neighbor_index);
+                            low_links.insert(vertex_id, low_link);
+                        }
+                        Some(_) => {}
+                    }
+                }
+                None => {
+                    // All the neighbors were explored, go back to the parent
+                    stack.pop();
+                    let Some(parent_id) = parent_id else {
+                        continue;
+                    };
+
+                    let low_link = low_links[parent_id].min(low_links[vertex_id]);
+                    low_links.insert(parent_id, low_link);
+
+                    // The subtree can not reach above the parent without it
+                    if low_links[vertex_id] >= indices[parent_id] {
+                        if stack.len() > 1 {
+                            articulation_points.insert(parent_id);
+                        } else {
+                            root_children += 1;
+                        }
+
+                        components.push(pop_component(&mut edge_stack, (parent_id, vertex_id)));
+                    }
+
+                    // The subtree can not reach the parent either without this edge
+                    if low_links[vertex_id] > indices[parent_id] {
+                        bridges.push(match graph.edge(parent_id, vertex_id) {
+                            Some(_) => (parent_id.clone(), vertex_id.clone()),
+                            None => (vertex_id.clone(), parent_id.clone()),
+                        });
+                    }
+                }
+            }
+        }
+
+        // The root is an articulation point if it has several subtrees
+        if root_children > 1 {
+            articulation_points.insert(root_id);
+        }
+    }
+
+    Biconnectivity {
+        articulation_points: articulation_points.into_iter().cloned().collect(),
+        bridges,
+        components,
+    }
+}
+
+/// Return the articulation points of the graph
+///
+/// See `biconnectivity`
+pub fn articulation_points<G: Graph + ?Sized>(graph: &G) -> Vec<VertexId<G>> {
+    biconnectivity(graph).articulation_points
+}
+
+/// Return the bridges of the graph, oriented along an edge of the graph
+///
+/// See `biconnectivity`
+pub fn bridges<G: Graph + ?Sized>(graph: &G) -> Vec<(VertexId<G>, VertexId<G>)> {
+    biconnectivity(graph).bridges
+}
+
+/// Return the vertex ids of each biconnected component of the graph
+///
+/// See `biconnectivity`
+pub fn biconnected_components<G: Graph + ?Sized>(graph: &G) -> Vec<Vec<VertexId<G>>> {
+    biconnectivity(graph).components
+}
+
+/// Pop the edges of the stack up to a given edge, and return their vertices
+fn pop_component<Id: Hash + Eq + Clone>(
+    edge_stack: &mut Vec<(&Id, &Id)>,
+    last_edge: (&Id, &Id),
+) -> Vec<Id> {
+    let mut seen = HashSet::new();
+    let mut component = Vec::new();
+
+    while let Some(edge) = edge_stack.pop() {
+        for id in [edge.0, edge.1] {
+            if seen.insert(id) {
+                component.push(id.clone());
+            }
+        }
+
+        if edge == last_edge {
+            break;
+        }
+    }
+
+    component
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::error::GraphError;
+    use super::super::graph_trait::GraphMut;
+    use super::super::oriented_weighted_graph::OrientedWeightedGraph;
+    use super::super::test_utils::build_oriented_graph;
+
+    /// Return the components with their vertices sorted, sorted
+    fn sorted_components(components: Vec<Vec<&'static str>>) -> Vec<Vec<&'static str>> {
+        let mut components: Vec<_> = components
+            .into_iter()
+            .map(|mut component| {
+                component.sort();
+                component
+            })
+            .collect();
+        components.sort();
+        components
+    }
+
+    #[test]
+    fn test_biconnectivity() -> Result<(), GraphError<&'static str>> {
+        // Two triangles a-b-c and c-d-e sharing c, a tail e - f,
+        // and a lonely vertex g
+        let graph = build_oriented_graph(
+            &["a", "b", "c", "d", "e", "f", "g"],
+            &[
+                ("a", "b"),
+                ("b", "c"),
+                ("c", "a"),
+                ("c", "d"),
+                ("d", "e"),
+                ("e", "c"),
+                ("f", "e"),
+            ],
+        )?;
+
+        let biconnectivity = graph.biconnectivity();
+
+        let mut articulation_points = biconnectivity.articulation_points.clone();
+        articulation_points.sort();
+        assert_eq!(articulation_points, vec!["c", "e"]);
+
+        // The bridge keeps the orientation of the edge
+        assert_eq!(biconnectivity.bridges, vec![("f", "e")]);
+        assert!(biconnectivity.is_bridge(&"e", &"f"));
+        assert!(!biconnectivity.is_bridge(&"c", &"d"));
+
+        assert_eq!(
+            sorted_components(biconnectivity.components),
+            vec![vec!["a", "b", "c"], vec!["c", "d", "e"], vec!["e", "f"]]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_orientation_is_ignored() -> Result<(), GraphError<&'static str>> {
+        // A path a - b - c, with an edge in each direction between a and b
+        let graph = build_oriented_graph(&["a", "b", "c"], &[("a", "b"), ("b", "a"), ("c", "b")])?;
+
+        assert_eq!(graph.articulation_points(), vec!["b"]);
+        assert_eq!(graph.bridges().len(), 2);
+        assert_eq!(
+            sorted_components(graph.biconnected_components()),
+            vec![vec!["a", "b"], vec!["b", "c"]]
+        );
+
+        // A cycle has no weak point, even with a self loop
+        let graph = build_oriented_graph(
+            &["a", "b", "c"],
+            &[("a", "b"), ("b", "c"), ("c", "a"), ("a", "a")],
+        )?;
+        assert!(graph.articulation_points().is_empty());
+        assert!(graph.bridges().is_empty());
+        assert_eq!(graph.biconnected_components().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_weighted_graph() -> Result<(), GraphError<&'static str>> {
+        // Two servers linked to a single router
+        let mut graph = OrientedWeightedGraph::new();
+        for vertex in ["router", "server1", "server2"] {
+            graph.add_vertex(vertex)?;
+        }
+        graph.add_edge(&"router", &"server1", 10)?;
+        graph.add_edge(&"router", &"server2", 20)?;
+
+        let biconnectivity = graph.biconnectivity();
+        assert!(biconnectivity.is_articulation_point(&"router"));
+        assert!(!biconnectivity.is_articulation_point(&"server1"));
+        assert_eq!(biconnectivity.bridges.len(), 2);
+        assert_eq!(biconnectivity.components.len(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,432 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use super::error::GraphError;
+use super::graph_trait::{undirected_neighbors, Graph, VertexId};
+
+/// Side of a vertex in a bipartite graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    /// Return the other side
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// Split of the vertices of a graph in two sides,
+/// such that every edge links a vertex of each side
+#[derive(Debug, Clone)]
+pub struct TwoColoring<Id> {
+    /// Side of each vertex
+    pub sides: HashMap<Id, Side>,
+}
+
+impl<Id: Hash + Eq> TwoColoring<Id> {
+    /// Return the side of a vertex, or None if it is not in the graph
+    pub fn side(&self, id: &Id) -> Option<Side> {
+        self.sides.get(id).copied()
+    }
+
+    /// Iterate over the vertices of the left side
+    pub fn left(&self) -> impl Iterator<Item = &Id> {
+        self.vertices_of(Side::Left)
+    }
+
+    /// Iterate over the vertices of the right side
+    pub fn right(&self) -> impl Iterator<Item = &Id> {
+        self.vertices_of(Side::Right)
+    }
+
+    fn vertices_of(&self, side: Side) -> impl Iterator<Item = &Id> {
+        self.sides
+            .iter()
+            .filter(move |(_, vertex_side)| **vertex_side == side)
+            .map(|(id, _)| id)
+    }
+}
+
+/// Result of the bipartite check
+pub type TwoColoringResult<G> = Result<TwoColoring<VertexId<G>>, GraphError<VertexId<G>>>;
+
+/// Result of the maximum matching algorithm
+pub type MatchingResult<G> = Result<Vec<(VertexId<G>, VertexId<G>)>, GraphError<VertexId<G>>>;
+
+/// Return a two-coloring of the graph if it is bipartite, or an `OddCycle`
+/// error carrying a cycle with an odd number of vertices, which proves
+/// that it is not
+///
+/// The graph is explored breadth first, giving to each vertex the opposite
+/// side of the vertex it was reached from. If an edge links two vertices of
+/// the same side, the paths from their common ancestor to both vertices and
+/// this edge make an odd cycle.
+///
+/// Note: the orientation of the edges is ignored
+pub fn is_bipartite<G: Graph + ?Sized>(graph: &G) -> TwoColoringResult<G> {
+    let neighbors = undirected_neighbors(graph);
+
+    let mut sides = HashMap::new();
+    // Vertex each vertex was reached from
+    let mut parents = HashMap::new();
+
+    for start_id in graph.vertex_ids() {
+        if sides.contains_key(start_id) {
+            continue;
+        }
+
+        sides.insert(start_id, Side::Left);
+        let mut queue = VecDeque::from([start_id]);
+
+        while let Some(vertex_id) = queue.pop_front() {
+            let side = sides[vertex_id];
+
+            for &neighbor_id in &neighbors[vertex_id] {
+                match sides.get(neighbor_id) {
+                    None => {
+                        sides.insert(neighbor_id, side.opposite());
+                        parents.insert(neighbor_id, vertex_id);
+                        queue.push_back(neighbor_id);
+                    }
+                    Some(&neighbor_side) if neighbor_side == side => {
+                        let cycle = odd_cycle(&parents, vertex_id, neighbor_id);
+                        return Err(GraphError::OddCycle(cycle));
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+
+    Ok(TwoColoring {
+        sides: sides
+            .into_iter()
+            .map(|(id, side)| (id.clone(), side))
+            .collect(),
+    })
+}
+
+/// Return the odd cycle closed by an edge between two vertices of the same side
+fn odd_cycle<'a, Id: Hash + Eq + Clone>(
+    parents: &HashMap<&'a Id, &'a Id>,
+    mut id1: &'a Id,
+    mut id2: &'a Id,
+) -> Vec<Id> {
+    // Vertices of the same side are at the same distance from the exploration
+    // start, so going up from both at the same pace meets at their common ancestor
+    let mut branch1 = Vec::new();
+    let mut branch2 = Vec::new();
+    while id1 != id2 {
+        branch1.push(id1);
+        branch2.push(id2);
+        id1 = parents[id1];
+        id2 = parents[id2];
+    }
+
+    // Go down to the first vertex, then come back up from the second one
+    let mut cycle = vec![id1.clone()];
+    cycle.extend(branch1.into_iter().rev().cloned());
+    cycle.extend(branch2.into_iter().cloned());
+
+    cycle
+}
+
+/// Return a maximum matching of a bipartite graph: the largest set of
+/// edges such that no vertex is touched by two of them, as (from, to) pairs
+///
+/// This function uses [Hopcroft-Karp algorithm](https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm):
+/// while a path alternating between unmatched and matched edges links two
+/// unmatched vertices, the matching grows by swapping the edges of the path.
+/// Each phase looks for as many shortest such paths as possible,
+/// so it runs in O(E * sqrt(V)).
+///
+/// It returns an `OddCycle` error if the graph is not bipartite.
+///
+/// Note: the orientation of the edges is ignored to find the matching,
+/// but the pairs follow the orientation of the matched edges
+pub fn hopcroft_karp<G: Graph + ?Sized>(graph: &G) -> MatchingResult<G> {
+    let coloring = is_bipartite(graph)?;
+    let neighbors = undirected_neighbors(graph);
+
+    // Give dense indices to the vertices of each side
+    let left_ids: Vec<&VertexId<G>> = coloring.left().collect();
+    let right_ids: Vec<&VertexId<G>> = coloring.right().collect();
+    let right_indices: HashMap<&VertexId<G>, usize> = right_ids
+        .iter()
+        .enumerate()
+        .map(|(index, id)| (*id, index))
+        .collect();
+
+    // As the graph is bipartite, the neighbors of left vertices are right vertices
+    let adjacency = left_ids
+        .iter()
+        .map(|id| {
+            neighbors[id]
+                .iter()
+                .map(|neighbor_id| right_indices[neighbor_id])
+                .collect()
+        })
+        .collect();
+
+    let mut matching = HopcroftKarp::new(adjacency, right_ids.len());
+    matching.run();
+
+    let pairs = matching
+        .left_mates
+        .iter()
+        .enumerate()
+        .filter_map(|(left, right)| {
+            let left_id = left_ids[left];
+            let right_id = right_ids[(*right)?];
+
+            match graph.edge(left_id, right_id) {
+                Some(_) => Some((left_id.clone(), right_id.clone())),
+                None => Some((right_id.clone(), left_id.clone())),
+            }
+        })
+        .collect();
+
+    Ok(pairs)
+}
+
+/// State of Hopcroft-Karp algorithm, on dense indices of each side
+struct HopcroftKarp {
+    /// Right vertices linked to each left vertex
+    adjacency: Vec<Vec<usize>>,
+
+    /// Right vertex matched with each left vertex
+    left_mates: Vec<Option<usize>>,
+
+    /// Left vertex matched with each right vertex
+    right_mates: Vec<Option<usize>>,
+
+    /// Layer of each left vertex in the current phase
+    /// (None if it can not be part of an augmenting path anymore)
+    layers: Vec<Option<usize>>,
+
+    /// Layer of the left vertices ending the shortest augmenting paths
+    /// in the current phase (None if there is no augmenting path)
+    last_layer: Option<usize>,
+}
+
+impl HopcroftKarp {
+    fn new(adjacency: Vec<Vec<usize>>, right_count: usize) -> Self {
+        let left_count = adjacency.len();
+
+        Self {
+            adjacency,
+            left_mates: vec![None; left_count],
+            right_mates: vec![None; right_count],
+            layers: vec![None; left_count],
+            last_layer: None,
+        }
+    }
+
+    /// Grow the matching until it is maximum
+    fn run(&mut self) {
+        while self.build_layers() {
+            for left in 0..self.adjacency.len() {
+                if self.left_mates[left].is_none() {
+                    self.augment(left);
+                }
+            }
+        }
+    }
+
+    /// Layer the left vertices by their distance from the unmatched left
+    /// vertices, walking alternatively unmatched and matched edges, and
+    /// denote if an unmatched right vertex can be reached
+    ///
+    /// Note: the layering stops at the first layer reaching an unmatched
+    /// right vertex, so only the shortest augmenting paths are followed,
+    /// which bounds the number of phases to O(√V)
+    fn build_layers(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+
+        for left in 0..self.adjacency.len() {
+            self.layers[left] = match self.left_mates[left] {
+                Some(_) => None,
+                None => {
+                    queue.push_back(left);
+                    Some(0)
+                }
+            };
+        }
+
+        self.last_layer = None;
+
+        while let Some(left) = queue.pop_front() {
+            // The layers beyond the shortest augmenting paths are not needed
+            if self.last_layer.is_some() && self.layers[left] >= self.last_layer {
+                continue;
+            }
+
+            for &right in &self.adjacency[left] {
+                match self.right_mates[right] {
+                    None => self.last_layer = self.last_layer.or(self.layers[left]),
+                    Some(next_left) if self.layers[next_left].is_none() => {
+                        self.layers[next_left] = self.layers[left].map(|layer| layer + 1);
+                        queue.push_back(next_left);
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        self.last_layer.is_some()
+    }
+
+    /// Look for an augmenting path from a left vertex through the layers,
+    /// swap its edges, and denote if one was found
+    fn augment(&mut self, left: usize) -> bool {
+        for index in 0..self.adjacency[left].len() {
+            let right = self.adjacency[left][index];
+
+            // Only the unmatched right vertices of the last layer end a path
+            let can_augment = match self.right_mates[right] {
+                None => self.layers[left] == self.last_layer,
+                Some(next_left) => {
+                    self.layers[left] < self.last_layer
+                        && self.layers[next_left] == self.layers[left].map(|layer| layer + 1)
+                        && self.augment(next_left)
+                }
+            };
+
+            if can_augment {
+                self.left_mates[left] = Some(right);
+                self.right_mates[right] = Some(left);
+                return true;
+            }
+        }
+
+        // No path goes through this vertex anymore in this phase
+        self.layers[left] = None;
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::graph_trait::GraphMut;
+    use super::super::oriented_graph::OrientedGraph;
+    use super::super::test_utils::{build_undirected_graph, rotate_cycle};
+    use super::*;
+
+    #[test]
+    fn test_is_bipartite() -> Result<(), GraphError<&'static str>> {
+        // A square is bipartite, the opposite corners are on the same side
+        let graph = build_undirected_graph(
+            &["a", "b", "c", "d", "e"],
+            &[("a", "b"), ("b", "c"), ("c", "d"), ("d", "a")],
+        )?;
+
+        let coloring = graph.is_bipartite()?;
+
+        assert_eq!(coloring.side(&"a"), coloring.side(&"c"));
+        assert_eq!(coloring.side(&"b"), coloring.side(&"d"));
+        assert_ne!(coloring.side(&"a"), coloring.side(&"b"));
+        assert!(coloring.side(&"e").is_some());
+        assert_eq!(coloring.left().count() + coloring.right().count(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_odd_cycle() -> Result<(), GraphError<&'static str>> {
+        // The cycle a, b, c, d, e has 5 vertices
+        let graph = build_undirected_graph(
+            &["a", "b", "c", "d", "e", "f"],
+            &[
+                ("a", "b"),
+                ("b", "c"),
+                ("c", "d"),
+                ("d", "e"),
+                ("e", "a"),
+                ("a", "f"),
+            ],
+        )?;
+
+        match graph.is_bipartite() {
+            Err(GraphError::OddCycle(cycle)) => {
+                // The cycle can go in any direction
+                let mut cycle = rotate_cycle(cycle, &"a");
+                if cycle[1] != "b" {
+                    cycle[1..].reverse();
+                }
+                assert_eq!(cycle, vec!["a", "b", "c", "d", "e"]);
+            }
+            result => panic!("Expected an odd cycle, got {:?}", result),
+        }
+
+        // A self loop is an odd cycle
+        let graph = build_undirected_graph(&["a"], &[("a", "a")])?;
+        assert_eq!(
+            graph.is_bipartite().err(),
+            Some(GraphError::OddCycle(vec!["a"]))
+        );
+        assert_eq!(
+            graph.hopcroft_karp().err(),
+            Some(GraphError::OddCycle(vec!["a"]))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hopcroft_karp() -> Result<(), GraphError<&'static str>> {
+        // Reviewers can review some of the tasks
+        let mut graph = OrientedGraph::new();
+        for vertex in ["alice", "bob", "carol", "dave", "task1", "task2", "task3"] {
+            graph.add_vertex(vertex)?;
+        }
+        for (reviewer, task) in [
+            ("alice", "task1"),
+            ("alice", "task2"),
+            ("bob", "task1"),
+            ("carol", "task2"),
+            ("carol", "task3"),
+            ("dave", "task3"),
+        ] {
+            graph.add_edge(&reviewer, &task)?;
+        }
+
+        let pairs = graph.hopcroft_karp()?;
+
+        // Each task gets its own reviewer
+        assert_eq!(pairs.len(), 3);
+        for (reviewer, task) in &pairs {
+            assert!(graph.edge_exists(reviewer, task));
+        }
+        let mut matched_vertices: Vec<_> = pairs
+            .iter()
+            .flat_map(|(reviewer, task)| [reviewer, task])
+            .collect();
+        matched_vertices.sort();
+        matched_vertices.dedup();
+        assert_eq!(matched_vertices.len(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hopcroft_karp_follows_shortest_augmenting_paths() {
+        // Left 1 is matched with right 1. From left 0, the direct edge to
+        // the free right 0 is shorter than the path 0 -> 1 -> 1 -> 2
+        let mut matching = HopcroftKarp::new(vec![vec![1, 0], vec![1, 2]], 3);
+        matching.left_mates[1] = Some(1);
+        matching.right_mates[1] = Some(1);
+
+        assert!(matching.build_layers());
+        assert_eq!(matching.last_layer, Some(0));
+        assert!(matching.augment(0));
+
+        assert_eq!(matching.left_mates, vec![Some(0), Some(1)]);
+        assert_eq!(matching.right_mates, vec![Some(0), Some(1), None]);
+    }
+}
@@ -0,0 +1,493 @@
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::ops::Range;
+
+use super::a_star;
+use super::bellman_ford;
+use super::error::GraphError;
+use super::graph_trait::{graph_neighbors, Graph};
+use super::identifiable::Identifiable;
+use super::shortest_path::{self, PathResult, ShortestPathsResult, VertexByDistance};
+use super::weight::Weight;
+
+/// An immutable graph stored in compressed sparse row (CSR) format
+///
+/// The vertices are numbered with dense indices (0 to n - 1), and the edges
+/// are stored in a single vector sorted by source vertex: the edges going
+/// out of the vertex `i` are at the positions `offsets[i]..offsets[i + 1]`.
+/// Its storage avoids the hash maps and sets of the other representations,
+/// so it is compact and fast to traverse, but it can not be modified once built.
+///
+/// It implements `Graph` (but not `GraphMut`), so the generic traversal and
+/// shortest path algorithms work on it, keeping their state in hash maps.
+/// The vertices can also be accessed by index: `breadth_first_depths` and
+/// `shortest_distances` use the indices to keep their state in vectors instead.
+///
+/// The weights are of type `W`, `()` for unweighted graphs
+#[derive(Debug, Clone)]
+pub struct CsrGraph<T: Identifiable, W = ()> {
+    /// Vertices, by index
+    vertices: Vec<T>,
+
+    /// Index of each vertex id
+    indices: HashMap<T::Id, usize>,
+
+    /// Position of the first edge of each vertex, with the number of edges at the end
+    offsets: Vec<usize>,
+
+    /// Target vertex index of each edge, sorted within the edges of a vertex
+    targets: Vec<usize>,
+
+    /// Weight of each edge
+    weights: Vec<W>,
+}
+
+impl<T: Identifiable, W> CsrGraph<T, W> {
+    /// Build a graph with the same vertices and edges than another graph
+    ///
+    /// The indices of the vertices follow the order of `Graph::vertices`.
+    pub fn from_graph<G>(graph: &G) -> Self
+    where
+        G: Graph<Vertex = T, Weight = W> + ?Sized,
+        T: Clone,
+        W: Clone,
+    {
+        let vertices: Vec<T> = graph.vertices().cloned().collect();
+        let indices = index_vertices(&vertices)
+            .unwrap_or_else(|_| unreachable!("the vertices come from a graph"));
+
+        let mut edges = Vec::with_capacity(graph.edge_count());
+        for (from, vertex) in vertices.iter().enumerate() {
+            for (neighbor_id, weight) in graph_neighbors(graph, vertex.get_id()) {
+                edges.push((from, indices[neighbor_id], weight.clone()));
+            }
+        }
+
+        Self::from_parts(vertices, indices, edges)
+            .unwrap_or_else(|_| unreachable!("the edges come from a graph"))
+    }
+
+    /// Build a graph from its vertices and a list of (from, to, weight) edges,
+    /// or Err if a vertex is duplicated, an edge is duplicated,
+    /// or an edge targets an invalid vertex
+    ///
+    /// The indices of the vertices follow the order of the input.
+    pub fn from_edges(
+        vertices: impl IntoIterator<Item = T>,
+        edges: impl IntoIterator<Item = (T::Id, T::Id, W)>,
+    ) -> Result<Self, GraphError<T::Id>> {
+        let vertices: Vec<T> = vertices.into_iter().collect();
+        let indices = index_vertices(&vertices)?;
+
+        let index_of = |id: &T::Id| {
+            indices
+                .get(id)
+                .copied()
+                .ok_or_else(|| GraphError::VertexNotFound(id.clone()))
+        };
+        let edges = edges
+            .into_iter()
+            .map(|(from, to, weight)| Ok((index_of(&from)?, index_of(&to)?, weight)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::from_parts(vertices, indices, edges)
+    }
+
+    /// Build the graph from indexed edges, or Err if an edge is duplicated
+    fn from_parts(
+        vertices: Vec<T>,
+        indices: HashMap<T::Id, usize>,
+        mut edges: Vec<(usize, usize, W)>,
+    ) -> Result<Self, GraphError<T::Id>> {
+        // Sort by source, then by target so the edges can be binary searched
+        edges.sort_by_key(|(from, to, _)| (*from, *to));
+
+        if let Some(duplicate) = edges
+            .windows(2)
+            .find(|pair| (pair[0].0, pair[0].1) == (pair[1].0, pair[1].1))
+        {
+            return Err(GraphError::EdgeAlreadyExists {
+                from: vertices[duplicate[0].0].get_id().clone(),
+                to: vertices[duplicate[0].1].get_id().clone(),
+            });
+        }
+
+        // Count the edges of each vertex, then accumulate the counts into positions
+        let mut offsets = vec![0; vertices.len() + 1];
+        for (from, _, _) in &edges {
+            offsets[from + 1] += 1;
+        }
+        for index in 0..vertices.len() {
+            offsets[index + 1] += offsets[index];
+        }
+
+        let (targets, weights) = edges
+            .into_iter()
+            .map(|(_, to, weight)| (to, weight))
+            .unzip();
+
+        Ok(Self {
+            vertices,
+            indices,
+            offsets,
+            targets,
+            weights,
+        })
+    }
+
+    /// Return the index of a vertex, if it is in the graph
+    pub fn index_of(&self, id: &T::Id) -> Option<usize> {
+        self.indices.get(id).copied()
+    }
+
+    /// Return the vertex at an index
+    ///
+    /// Note: indices out of range panic, like an out of bounds vector index
+    pub fn vertex_at(&self, index: usize) -> &T {
+        &self.vertices[index]
+    }
+
+    /// Return the number of edges going out of the vertex at an index
+    pub fn out_degree(&self, index: usize) -> usize {
+        self.edge_range(index).len()
+    }
+
+    /// Iterate over the edges going out of the vertex at an index,
+    /// as (neighbor index, weight) pairs sorted by neighbor index
+    pub fn neighbor_indices(&self, index: usize) -> impl Iterator<Item = (usize, &W)> {
+        let range = self.edge_range(index);
+
+        self.targets[range.clone()]
+            .iter()
+            .copied()
+            .zip(&self.weights[range])
+    }
+
+    /// Return the weight of the edge between the vertices at two indices, if any
+    pub fn edge_at(&self, from: usize, to: usize) -> Option<&W> {
+        let range = self.edge_range(from);
+
+        self.targets[range.clone()]
+            .binary_search(&to)
+            .ok()
+            .map(|position| &self.weights[range.start + position])
+    }
+
+    /// Return the depth of each vertex (by index) in a breadth first traversal
+    /// from the vertex at an index, or None if it can not be reached
+    ///
+    /// Note: indices out of range panic, like an out of bounds vector index
+    pub fn breadth_first_depths(&self, start: usize) -> Vec<Option<usize>> {
+        let mut depths = vec![None; self.vertices.len()];
+        let mut queue = VecDeque::new();
+
+        // Vertices are marked with their depth when queued, so they are queued only once
+        depths[start] = Some(0);
+        queue.push_back((start, 0));
+
+        while let Some((index, depth)) = queue.pop_front() {
+            for (neighbor, _) in self.neighbor_indices(index) {
+                if depths[neighbor].is_none() {
+                    depths[neighbor] = Some(depth + 1);
+                    queue.push_back((neighbor, depth + 1));
+                }
+            }
+        }
+
+        depths
+    }
+
+    /// Return the positions of the edges going out of the vertex at an index
+    fn edge_range(&self, index: usize) -> Range<usize> {
+        self.offsets[index]..self.offsets[index + 1]
+    }
+}
+
+impl<T: Identifiable, W: Weight> CsrGraph<T, W> {
+    /// Return the shortest distance from the vertex at an index to each vertex
+    /// (by index), or None if it can not be reached, and Err if a reachable
+    /// edge has a negative weight or a vertex can only be reached by paths
+    /// whose weight does not fit in the weight type
+    ///
+    /// This is Dijkstra's algorithm (see `shortest_path::shortest_paths_from`),
+    /// with its state stored in vectors.
+    /// Note: indices out of range panic, like an out of bounds vector index
+    pub fn shortest_distances(&self, source: usize) -> Result<Vec<Option<W>>, GraphError<T::Id>> {
+        let mut distances = vec![None; self.vertices.len()];
+        let mut seen = vec![false; self.vertices.len()];
+        let mut priority_queue = BinaryHeap::new();
+        let mut overflowing_edges = Vec::new();
+
+        distances[source] = Some(W::zero());
+        priority_queue.push(VertexByDistance {
+            id: source,
+            distance: W::zero(),
+        });
+
+        while let Some(VertexByDistance {
+            id: index,
+            distance,
+        }) = priority_queue.pop()
+        {
+            // A vertex can be queued several times, only its first visit is relevant
+            if seen[index] {
+                continue;
+            }
+            seen[index] = true;
+
+            for (neighbor, weight) in self.neighbor_indices(index) {
+                shortest_path::non_negative_weight_or_err(
+                    self.vertices[index].get_id(),
+                    self.vertices[neighbor].get_id(),
+                    *weight,
+                )?;
+
+                let Some(neighbor_distance) = distance.checked_add(*weight) else {
+                    overflowing_edges.push((index, neighbor));
+                    continue;
+                };
+
+                if distances[neighbor].is_none_or(|known| neighbor_distance < known) {
+                    distances[neighbor] = Some(neighbor_distance);
+                    priority_queue.push(VertexByDistance {
+                        id: neighbor,
+                        distance: neighbor_distance,
+                    });
+                }
+            }
+        }
+
+        // The overflowing paths only matter if the vertex was not reached otherwise
+        match overflowing_edges
+            .into_iter()
+            .find(|(_, neighbor)| distances[*neighbor].is_none())
+        {
+            Some((index, neighbor)) => Err(GraphError::WeightOverflow {
+                from: self.vertices[index].get_id().clone(),
+                to: self.vertices[neighbor].get_id().clone(),
+            }),
+            None => Ok(distances),
+        }
+    }
+
+    /// Return the shortest path between two vertices of the graph,
+    /// or None if the target can not be reached from the source
+    ///
+    /// See `shortest_path::shortest_path`
+    pub fn shortest_path(&self, source_id: &T::Id, target_id: &T::Id) -> PathResult<Self> {
+        shortest_path::shortest_path(self, source_id, target_id)
+    }
+
+    /// Return the shortest paths from a source vertex to every vertex it can reach
+    ///
+    /// See `shortest_path::shortest_paths_from`
+    pub fn shortest_paths_from(&self, source_id: &T::Id) -> ShortestPathsResult<Self> {
+        shortest_path::shortest_paths_from(self, source_id)
+    }
+
+    /// Return the shortest path between two vertices of the graph, using an heuristic
+    /// estimating the distance between a vertex and the target to explore less vertices
+    ///
+    /// See `a_star::a_star`
+    pub fn a_star(
+        &self,
+        source_id: &T::Id,
+        target_id: &T::Id,
+        heuristic: impl Fn(&T) -> W,
+    ) -> PathResult<Self> {
+        a_star::a_star(self, source_id, target_id, heuristic)
+    }
+
+    /// Return the shortest paths from a source vertex to every vertex it can reach,
+    /// supporting negative weights, or Err if a negative cycle can be reached
+    ///
+    /// See `bellman_ford::bellman_ford`
+    pub fn bellman_ford(&self, source_id: &T::Id) -> ShortestPathsResult<Self> {
+        bellman_ford::bellman_ford(self, source_id)
+    }
+}
+
+impl<T: Identifiable, W> Graph for CsrGraph<T, W> {
+    type Vertex = T;
+    type Weight = W;
+
+    fn vertex(&self, id: &T::Id) -> Option<&T> {
+        self.index_of(id).map(|index| &self.vertices[index])
+    }
+
+    fn vertices(&self) -> impl Iterator<Item = &T> {
+        self.vertices.iter()
+    }
+
+    fn vertex_ids(&self) -> impl Iterator<Item = &T::Id> {
+        self.vertices.iter().map(|vertex| vertex.get_id())
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        id: &T::Id,
+    ) -> Result<impl Iterator<Item = (&'a T::Id, &'a W)> + use<'a, T, W>, GraphError<T::Id>> {
+        let index = self
+            .index_of(id)
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))?;
+
+        Ok(self
+            .neighbor_indices(index)
+            .map(|(neighbor, weight)| (self.vertices[neighbor].get_id(), weight)))
+    }
+
+    fn edge(&self, from: &T::Id, to: &T::Id) -> Option<&W> {
+        self.edge_at(self.index_of(from)?, self.index_of(to)?)
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.targets.len()
+    }
+}
+
+/// Return the index of each vertex id, or Err if a vertex is duplicated
+fn index_vertices<T: Identifiable>(
+    vertices: &[T],
+) -> Result<HashMap<T::Id, usize>, GraphError<T::Id>> {
+    let mut indices = HashMap::with_capacity(vertices.len());
+
+    for (index, vertex) in vertices.iter().enumerate() {
+        if indices.insert(vertex.get_id().clone(), index).is_some() {
+            return Err(GraphError::VertexAlreadyExists(vertex.get_id().clone()));
+        }
+    }
+
+    Ok(indices)
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::graph_trait::GraphMut;
+    use super::super::oriented_graph::OrientedGraph;
+    use super::super::test_utils::build_weighted_graph;
+    use super::*;
+
+    #[test]
+    fn test_from_graph() -> Result<(), GraphError<&'static str>> {
+        let graph = build_weighted_graph(
+            &["a", "b", "c", "d", "e", "f"],
+            &[
+                ("a", "b", 4),
+                ("a", "c", 2),
+                ("b", "e", 3),
+                ("c", "d", 2),
+                ("c", "f", 4),
+                ("d", "e", 3),
+                ("d", "f", 1),
+                ("f", "e", 1),
+            ],
+        )?;
+        let csr_graph = CsrGraph::from_graph(&graph);
+
+        assert_eq!(csr_graph.vertex_count(), 6);
+        assert_eq!(csr_graph.edge_count(), 8);
+        for from in graph.vertex_ids() {
+            for to in graph.vertex_ids() {
+                assert_eq!(csr_graph.edge(from, to), graph.edge(from, to));
+            }
+        }
+
+        // Same shortest paths than the original graph
+        for source in graph.vertex_ids() {
+            assert_eq!(
+                csr_graph.shortest_paths_from(source)?.distances,
+                graph.shortest_paths_from(source)?.distances
+            );
+        }
+        let path = csr_graph.shortest_path(&"a", &"e")?.unwrap();
+        assert_eq!(path.vertices, vec!["a", "c", "d", "f", "e"]);
+        assert_eq!(path.total_weight, 6);
+        assert_eq!(
+            csr_graph.bellman_ford(&"a")?.distances,
+            graph.bellman_ford(&"a")?.distances
+        );
+
+        // Same distances by index
+        let tree = graph.shortest_paths_from(&"c")?;
+        let distances = csr_graph.shortest_distances(csr_graph.index_of(&"c").unwrap())?;
+        for (index, distance) in distances.into_iter().enumerate() {
+            assert_eq!(distance, tree.distance_to(csr_graph.vertex_at(index)));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_unweighted_graph() -> Result<(), GraphError<u32>> {
+        let mut graph = OrientedGraph::new();
+        for vertex in 0u32..4 {
+            graph.add_vertex(vertex)?;
+        }
+        graph.add_edge(&0, &1)?;
+        graph.add_edge(&1, &2)?;
+        graph.add_edge(&0, &3)?;
+
+        let csr_graph = CsrGraph::from_graph(&graph);
+
+        // Traversals reach the same vertices at the same depth
+        let mut visits: Vec<_> = csr_graph
+            .bfs(&0)?
+            .map(|visit| (*visit.vertex, visit.depth))
+            .collect();
+        visits.sort();
+        assert_eq!(visits, vec![(0, 0), (1, 1), (2, 2), (3, 1)]);
+        assert_eq!(csr_graph.traverse_depth_first_iterative(&2)?, vec![2]);
+
+        // Same depths by index
+        let depths = csr_graph.breadth_first_depths(csr_graph.index_of(&0).unwrap());
+        for (index, depth) in depths.into_iter().enumerate() {
+            let expected = visits
+                .iter()
+                .find(|(vertex, _)| vertex == csr_graph.vertex_at(index))
+                .map(|(_, depth)| *depth);
+            assert_eq!(depth, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_edges() -> Result<(), GraphError<&'static str>> {
+        let csr_graph = CsrGraph::from_edges(
+            ["a", "b", "c"],
+            [("b", "c", 5), ("a", "c", 1), ("a", "b", 2)],
+        )?;
+
+        // The indices follow the order of the vertices, and the edges
+        // are sorted by neighbor index
+        assert_eq!(csr_graph.index_of(&"c"), Some(2));
+        assert_eq!(csr_graph.vertex_at(1), &"b");
+        assert_eq!(csr_graph.out_degree(0), 2);
+        assert_eq!(
+            csr_graph.neighbor_indices(0).collect::<Vec<_>>(),
+            vec![(1, &2), (2, &1)]
+        );
+        assert_eq!(csr_graph.edge_at(1, 2), Some(&5));
+        assert_eq!(csr_graph.edge_at(2, 1), None);
+        assert_eq!(csr_graph.out_degree(2), 0);
+
+        // Invalid vertices and edges should Err
+        assert_eq!(
+            CsrGraph::from_edges(["a", "a"], Vec::<(&str, &str, u32)>::new()).err(),
+            Some(GraphError::VertexAlreadyExists("a"))
+        );
+        assert_eq!(
+            CsrGraph::from_edges(["a"], [("a", "z", 1)]).err(),
+            Some(GraphError::VertexNotFound("z"))
+        );
+        assert_eq!(
+            CsrGraph::from_edges(["a", "b"], [("a", "b", 1), ("a", "b", 2)]).err(),
+            Some(GraphError::EdgeAlreadyExists { from: "a", to: "b" })
+        );
+
+        Ok(())
+    }
+}
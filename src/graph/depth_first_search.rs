@@ -0,0 +1,413 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::error::GraphError;
+use super::graph_trait::{graph_neighbors, Graph, VertexId};
+use super::identifiable::Identifiable;
+
+/// What the search should do after an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Control {
+    /// Go on with the search
+    #[default]
+    Continue,
+
+    /// Do not go further from here: after a vertex discovery, its edges are
+    /// not explored, and after a tree edge, the edge is not followed
+    /// (it has no effect on the other events)
+    Prune,
+
+    /// Stop the whole search
+    Abort,
+}
+
+/// Visitor receiving the events of a depth first search
+///
+/// Every method does nothing and continues the search by default,
+/// so a visitor only implements the events it is interested in.
+///
+/// The edges are classified according to the depth first search tree:
+/// - Tree edge: it leads to a new vertex, which becomes a child of the current vertex
+/// - Back edge: it leads to an ancestor of the current vertex (it closes a cycle)
+/// - Forward edge: it leads to a descendant of the current vertex already finished
+/// - Cross edge: it leads to any other vertex already finished
+///
+/// Note: edges are considered oriented, so for undirected graphs each edge
+/// is seen twice (for example a tree edge, then a back edge to the parent)
+#[allow(unused_variables)]
+pub trait DfsVisitor<Id> {
+    /// A vertex is reached for the first time
+    fn discover_vertex(&mut self, id: &Id, time: usize) -> Control {
+        Control::Continue
+    }
+
+    /// An edge leads to a vertex that was never reached
+    fn tree_edge(&mut self, from: &Id, to: &Id) -> Control {
+        Control::Continue
+    }
+
+    /// An edge leads to an ancestor of the current vertex
+    fn back_edge(&mut self, from: &Id, to: &Id) -> Control {
+        Control::Continue
+    }
+
+    /// An edge leads to a finished descendant of the current vertex
+    fn forward_edge(&mut self, from: &Id, to: &Id) -> Control {
+        Control::Continue
+    }
+
+    /// An edge leads to a finished vertex which is not a descendant of the current vertex
+    fn cross_edge(&mut self, from: &Id, to: &Id) -> Control {
+        Control::Continue
+    }
+
+    /// All the edges of a vertex were explored
+    fn finish_vertex(&mut self, id: &Id, time: usize) -> Control {
+        Control::Continue
+    }
+}
+
+/// Timestamps of a depth first search
+///
+/// A single clock ticks at each vertex discovery and finish, so a vertex
+/// is a descendant of another one if and only if its discovery and finish
+/// times are between the discovery and finish times of the other one.
+#[derive(Debug, Clone)]
+pub struct DfsTimestamps<Id> {
+    /// Time each reached vertex was discovered
+    pub discovery: HashMap<Id, usize>,
+
+    /// Time each finished vertex was finished
+    pub finish: HashMap<Id, usize>,
+
+    /// Denote if the visitor aborted the search
+    pub aborted: bool,
+}
+
+/// Search the whole graph depth first, sending the events to the visitor,
+/// and return the timestamps of the vertices
+///
+/// A search starts from each vertex not reached by the previous ones,
+/// so every vertex is reached (unless the visitor prunes or aborts the search).
+pub fn depth_first_search<G, V>(graph: &G, visitor: &mut V) -> DfsTimestamps<VertexId<G>>
+where
+    G: Graph + ?Sized,
+    V: DfsVisitor<VertexId<G>>,
+{
+    let mut search = Search::new(graph, visitor);
+
+    for start_id in graph.vertex_ids() {
+        if !search.discovery.contains_key(start_id)
+            && search.search_from(start_id) == Control::Abort
+        {
+            return search.into_timestamps(true);
+        }
+    }
+
+    search.into_timestamps(false)
+}
+
+/// Search the graph depth first from a start vertex, sending the events to
+/// the visitor, and return the timestamps of the vertices, or Err if the
+/// start vertex does not exist
+pub fn depth_first_search_from<G, V>(
+    graph: &G,
+    start_id: &VertexId<G>,
+    visitor: &mut V,
+) -> Result<DfsTimestamps<VertexId<G>>, GraphError<VertexId<G>>>
+where
+    G: Graph + ?Sized,
+    V: DfsVisitor<VertexId<G>>,
+{
+    let start_id = graph
+        .vertex(start_id)
+        .ok_or_else(|| GraphError::VertexNotFound(start_id.clone()))?
+        .get_id();
+
+    let mut search = Search::new(graph, visitor);
+    let aborted = search.search_from(start_id) == Control::Abort;
+
+    Ok(search.into_timestamps(aborted))
+}
+
+/// State of a depth first search
+struct Search<'a, 'v, G: Graph + ?Sized, V> {
+    graph: &'a G,
+    visitor: &'v mut V,
+
+    /// Current time
+    clock: usize,
+
+    /// Discovery time of the reached vertices
+    discovery: HashMap<&'a VertexId<G>, usize>,
+
+    /// Finish time of the finished vertices
+    finish: HashMap<&'a VertexId<G>, usize>,
+}
+
+impl<'a, 'v, G, V> Search<'a, 'v, G, V>
+where
+    G: Graph + ?Sized,
+    V: DfsVisitor<VertexId<G>>,
+{
+    fn new(graph: &'a G, visitor: &'v mut V) -> Self {
+        Self {
+            graph,
+            visitor,
+            clock: 0,
+            discovery: HashMap::new(),
+            finish: HashMap::new(),
+        }
+    }
+
+    /// Search from a vertex that was not reached yet, and return Abort
+    /// if the visitor aborted the search
+    fn search_from(&mut self, start_id: &'a VertexId<G>) -> Control {
+        // Path from the start vertex to the current vertex, with the
+        // neighbors left to explore for each vertex of the path
+        let mut stack = Vec::new();
+
+        match self.discover(start_id) {
+            Control::Abort => return Control::Abort,
+            Control::Prune => return self.finish(start_id),
+            Control::Continue => stack.push((start_id, graph_neighbors(self.graph, start_id))),
+        }
+
+        while let Some((vertex_id, neighbors)) = stack.last_mut() {
+            let vertex_id = *vertex_id;
+
+            let Some((neighbor_id, _)) = neighbors.next() else {
+                // All the neighbors were explored, go back
+                stack.pop();
+                if self.finish(vertex_id) == Control::Abort {
+                    return Control::Abort;
+                }
+                continue;
+            };
+
+            let control = if !self.discovery.contains_key(neighbor_id) {
+                // Follow the tree edge, unless pruned
+                match self.visitor.tree_edge(vertex_id, neighbor_id) {
+                    Control::Continue => match self.discover(neighbor_id) {
+                        Control::Continue => {
+                            stack.push((neighbor_id, graph_neighbors(self.graph, neighbor_id)));
+                            Control::Continue
+                        }
+                        Control::Prune => self.finish(neighbor_id),
+                        Control::Abort => Control::Abort,
+                    },
+                    control => control,
+                }
+            } else if !self.finish.contains_key(neighbor_id) {
+                self.visitor.back_edge(vertex_id, neighbor_id)
+            } else if self.discovery[vertex_id] < self.discovery[neighbor_id] {
+                self.visitor.forward_edge(vertex_id, neighbor_id)
+            } else {
+                self.visitor.cross_edge(vertex_id, neighbor_id)
+            };
+
+            if control == Control::Abort {
+                return Control::Abort;
+            }
+        }
+
+        Control::Continue
+    }
+
+    /// Stamp the discovery of a vertex, and send the event
+    fn discover(&mut self, id: &'a VertexId<G>) -> Control {
+        self.discovery.insert(id, self.clock);
+        self.clock += 1;
+
+        self.visitor.discover_vertex(id, self.discovery[id])
+    }
+
+    /// Stamp the finish of a vertex, and send the event
+    fn finish(&mut self, id: &'a VertexId<G>) -> Control {
+        self.finish.insert(id, self.clock);
+        self.clock += 1;
+
+        // Pruning a finished vertex has no effect
+        match self.visitor.finish_vertex(id, self.finish[id]) {
+            Control::Abort => Control::Abort,
+            _ => Control::Continue,
+        }
+    }
+
+    fn into_timestamps(self, aborted: bool) -> DfsTimestamps<VertexId<G>> {
+        DfsTimestamps {
+            discovery: clone_keys(self.discovery),
+            finish: clone_keys(self.finish),
+            aborted,
+        }
+    }
+}
+
+/// Return a map owning its keys
+fn clone_keys<Id: Hash + Eq + Clone>(map: HashMap<&Id, usize>) -> HashMap<Id, usize> {
+    map.into_iter()
+        .map(|(id, time)| (id.clone(), time))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::build_oriented_graph;
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    enum EdgeKind {
+        Tree,
+        Back,
+        Forward,
+        Cross,
+    }
+
+    /// Visitor recording the edges, and pruning or aborting on given vertices
+    #[derive(Default)]
+    struct EdgeRecorder {
+        edges: Vec<(EdgeKind, &'static str, &'static str)>,
+        finished: Vec<&'static str>,
+        prune_on: Option<&'static str>,
+        abort_on: Option<&'static str>,
+    }
+
+    impl EdgeRecorder {
+        fn record(&mut self, kind: EdgeKind, from: &&'static str, to: &&'static str) -> Control {
+            self.edges.push((kind, from, to));
+            Control::Continue
+        }
+
+        fn sorted_edges(&self) -> Vec<(EdgeKind, &'static str, &'static str)> {
+            let mut edges = self.edges.clone();
+            edges.sort();
+            edges
+        }
+    }
+
+    impl DfsVisitor<&'static str> for EdgeRecorder {
+        fn discover_vertex(&mut self, id: &&'static str, _time: usize) -> Control {
+            if self.abort_on == Some(id) {
+                Control::Abort
+            } else if self.prune_on == Some(id) {
+                Control::Prune
+            } else {
+                Control::Continue
+            }
+        }
+
+        fn tree_edge(&mut self, from: &&'static str, to: &&'static str) -> Control {
+            self.record(EdgeKind::Tree, from, to)
+        }
+
+        fn back_edge(&mut self, from: &&'static str, to: &&'static str) -> Control {
+            self.record(EdgeKind::Back, from, to)
+        }
+
+        fn forward_edge(&mut self, from: &&'static str, to: &&'static str) -> Control {
+            self.record(EdgeKind::Forward, from, to)
+        }
+
+        fn cross_edge(&mut self, from: &&'static str, to: &&'static str) -> Control {
+            self.record(EdgeKind::Cross, from, to)
+        }
+
+        fn finish_vertex(&mut self, id: &&'static str, _time: usize) -> Control {
+            self.finished.push(id);
+            Control::Continue
+        }
+    }
+
+    #[test]
+    fn test_edge_classification() -> Result<(), GraphError<&'static str>> {
+        // a -> b -> c -> a is a cycle, and a -> c a shortcut
+        let graph = build_oriented_graph(
+            &["a", "b", "c"],
+            &[("a", "b"), ("b", "c"), ("c", "a"), ("a", "c")],
+        )?;
+
+        let mut visitor = EdgeRecorder::default();
+        let timestamps = graph.depth_first_search_from(&"a", &mut visitor)?;
+
+        // The shortcut is either followed first, or found once c is finished
+        let mut expected_edges = match timestamps.discovery[&"b"] < timestamps.discovery[&"c"] {
+            true => vec![
+                (EdgeKind::Tree, "a", "b"),
+                (EdgeKind::Tree, "b", "c"),
+                (EdgeKind::Back, "c", "a"),
+                (EdgeKind::Forward, "a", "c"),
+            ],
+            false => vec![
+                (EdgeKind::Tree, "a", "b"),
+                (EdgeKind::Tree, "a", "c"),
+                (EdgeKind::Back, "c", "a"),
+                (EdgeKind::Cross, "b", "c"),
+            ],
+        };
+        expected_edges.sort();
+        assert_eq!(visitor.sorted_edges(), expected_edges);
+
+        // The clock ticks at each discovery and finish
+        assert_eq!(timestamps.discovery[&"a"], 0);
+        assert_eq!(timestamps.finish[&"a"], 5);
+        assert_eq!(visitor.finished.last(), Some(&"a"));
+        assert!(!timestamps.aborted);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cross_edges_between_trees() -> Result<(), GraphError<&'static str>> {
+        let graph = build_oriented_graph(&["a", "b"], &[("b", "a")])?;
+
+        let mut visitor = EdgeRecorder::default();
+        let timestamps = graph.depth_first_search(&mut visitor);
+
+        // b -> a is a tree edge if b is searched first, else a cross edge
+        let expected_kind = match timestamps.discovery[&"b"] < timestamps.discovery[&"a"] {
+            true => EdgeKind::Tree,
+            false => EdgeKind::Cross,
+        };
+        assert_eq!(visitor.edges, vec![(expected_kind, "b", "a")]);
+        assert_eq!(timestamps.finish.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prune_and_abort() -> Result<(), GraphError<&'static str>> {
+        let graph =
+            build_oriented_graph(&["a", "b", "c", "d"], &[("a", "b"), ("b", "c"), ("c", "d")])?;
+
+        // The edges of b are not explored
+        let mut visitor = EdgeRecorder {
+            prune_on: Some("b"),
+            ..Default::default()
+        };
+        let timestamps = graph.depth_first_search_from(&"a", &mut visitor)?;
+        assert_eq!(visitor.edges, vec![(EdgeKind::Tree, "a", "b")]);
+        assert_eq!(visitor.finished, vec!["b", "a"]);
+        assert!(!timestamps.discovery.contains_key(&"c"));
+
+        // The search stops on c
+        let mut visitor = EdgeRecorder {
+            abort_on: Some("c"),
+            ..Default::default()
+        };
+        let timestamps = graph.depth_first_search_from(&"a", &mut visitor)?;
+        assert!(timestamps.aborted);
+        assert!(timestamps.discovery.contains_key(&"c"));
+        assert!(!timestamps.discovery.contains_key(&"d"));
+        assert!(visitor.finished.is_empty());
+
+        // Unknown start vertex should Err
+        assert_eq!(
+            graph
+                .depth_first_search_from(&"z", &mut EdgeRecorder::default())
+                .err(),
+            Some(GraphError::VertexNotFound("z"))
+        );
+
+        Ok(())
+    }
+}
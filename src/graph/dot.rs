@@ -0,0 +1,797 @@
+//! Export and import of graphs in the [DOT language](https://graphviz.org/doc/info/lang.html)
+//! used by Graphviz
+//!
+//! The export writes the vertices and edges sorted by id, so the output of
+//! a graph is stable and can be kept as a fixture. The import supports the
+//! subset of DOT needed to describe an oriented graph: a `digraph` made of
+//! vertex and edge statements (edges can be chained, `A -> B -> C`),
+//! attributes, and comments. In a `strict` graph, the statements of the same
+//! edge are merged. Subgraphs and undirected graphs are not supported.
+
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+
+use super::error::GraphError;
+use super::graph_trait::{graph_neighbors, Graph, GraphMut, VertexId};
+use super::identifiable::Identifiable;
+use super::oriented_graph::OrientedGraph;
+use super::oriented_weighted_graph::OrientedWeightedGraph;
+
+/// Errors returned when building a graph from DOT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DotError<Id> {
+    /// The input is not valid DOT, uses an unsupported feature, has an
+    /// invalid weight, or a vertex id the vertex constructor failed to build
+    Syntax { line: usize, message: String },
+
+    /// The graph can not be built, for example because of a duplicated edge
+    Graph(GraphError<Id>),
+}
+
+impl<Id: Display> Display for DotError<Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DotError::Syntax { line, message } => write!(f, "Line {}: {}", line, message),
+            DotError::Graph(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl<Id: fmt::Debug + Display> Error for DotError<Id> {}
+
+impl<Id> From<GraphError<Id>> for DotError<Id> {
+    fn from(error: GraphError<Id>) -> Self {
+        DotError::Graph(error)
+    }
+}
+
+/// Return the DOT representation of the graph
+///
+/// `edge_label` returns the label of an edge from its weight (None for no label),
+/// and the vertices and edges of `highlighted_path` are drawn in red.
+pub fn to_dot<G: Graph + ?Sized>(
+    graph: &G,
+    edge_label: impl Fn(&G::Weight) -> Option<String>,
+    highlighted_path: &[VertexId<G>],
+) -> String
+where
+    VertexId<G>: Display,
+{
+    let highlighted_vertices: HashSet<_> = highlighted_path.iter().collect();
+    let highlighted_edges: HashSet<_> = highlighted_path
+        .windows(2)
+        .map(|edge| (&edge[0], &edge[1]))
+        .collect();
+
+    // Sort by quoted id, so the output does not depend on the hash order
+    let mut vertex_ids: Vec<_> = graph.vertex_ids().map(|id| (quote(id), id)).collect();
+    vertex_ids.sort_by(|(name1, _), (name2, _)| name1.cmp(name2));
+
+    let mut dot = String::from("digraph {\n");
+
+    for (name, id) in &vertex_ids {
+        match highlighted_vertices.contains(id) {
+            true => writeln!(dot, "    {} [color=red];", name).unwrap(),
+            false => writeln!(dot, "    {};", name).unwrap(),
+        }
+    }
+
+    for (from_name, from_id) in &vertex_ids {
+        let mut neighbors: Vec<_> = graph_neighbors(graph, from_id)
+            .map(|(to_id, weight)| (quote(to_id), to_id, weight))
+            .collect();
+        neighbors.sort_by(|(name1, _, _), (name2, _, _)| name1.cmp(name2));
+
+        for (to_name, to_id, weight) in neighbors {
+            let mut attributes = Vec::new();
+            if let Some(label) = edge_label(weight) {
+                attributes.push(format!("label={}", quote(&label)));
+            }
+            if highlighted_edges.contains(&(*from_id, to_id)) {
+                attributes.push("color=red".to_string());
+                attributes.push("penwidth=2".to_string());
+            }
+
+            match attributes.is_empty() {
+                true => writeln!(dot, "    {} -> {};", from_name, to_name).unwrap(),
+                false => writeln!(
+                    dot,
+                    "    {} -> {} [{}];",
+                    from_name,
+                    to_name,
+                    attributes.join(", ")
+                )
+                .unwrap(),
+            }
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Build an oriented graph from its DOT representation
+///
+/// The vertices are built from their DOT ids by `vertex`, whose errors are
+/// returned as syntax errors. The vertices only named in edges are added
+/// too, and the attributes are ignored.
+/// In a `strict` graph, the statements of the same edge are merged.
+pub fn parse_oriented_graph<T: Identifiable, E: Display>(
+    input: &str,
+    vertex: impl Fn(&str) -> Result<T, E>,
+) -> Result<OrientedGraph<T>, DotError<T::Id>> {
+    let statements = parse(input)?;
+    let mut graph = OrientedGraph::new();
+    let mut ids = HashMap::new();
+
+    for (name, line) in &statements.vertices {
+        add_vertex_once(&mut graph, &mut ids, name, *line, &vertex)?;
+    }
+    for edge in &statements.edges {
+        let from = add_vertex_once(&mut graph, &mut ids, &edge.from, edge.line, &vertex)?;
+        let to = add_vertex_once(&mut graph, &mut ids, &edge.to, edge.line, &vertex)?;
+
+        // A strict graph has at most one edge between two vertices
+        if statements.strict && graph.edge_exists(&from, &to) {
+            continue;
+        }
+        graph.add_edge(&from, &to)?;
+    }
+
+    Ok(graph)
+}
+
+/// Build an oriented weighted graph from its DOT representation
+///
+/// The vertices are built from their DOT ids by `vertex`, whose errors are
+/// returned as syntax errors. The vertices only named in edges are added
+/// too. The weight of an edge is parsed from its `label` attribute, or its
+/// `weight` attribute if it has no label.
+/// In a `strict` graph, the statements of the same edge are merged: the
+/// edge keeps the last weight given to it.
+pub fn parse_oriented_weighted_graph<T, W, E>(
+    input: &str,
+    vertex: impl Fn(&str) -> Result<T, E>,
+) -> Result<OrientedWeightedGraph<T, W>, DotError<T::Id>>
+where
+    T: Identifiable,
+    W: FromStr,
+    E: Display,
+{
+    let statements = parse(input)?;
+    let mut graph = OrientedWeightedGraph::new();
+    let mut ids = HashMap::new();
+
+    for (name, line) in &statements.vertices {
+        add_vertex_once(&mut graph, &mut ids, name, *line, &vertex)?;
+    }
+    for edge in &statements.edges {
+        let from = add_vertex_once(&mut graph, &mut ids, &edge.from, edge.line, &vertex)?;
+        let to = add_vertex_once(&mut graph, &mut ids, &edge.to, edge.line, &vertex)?;
+
+        let weight = edge
+            .attributes
+            .get("label")
+            .or_else(|| edge.attributes.get("weight"));
+
+        // A strict graph has at most one edge between two vertices,
+        // which keeps the last weight
+        if statements.strict && graph.edge_exists(&from, &to) {
+            if let Some(weight) = weight {
+                graph.set_weight(&from, &to, parse_weight(weight, edge.line)?)?;
+            }
+            continue;
+        }
+
+        let weight = weight.ok_or_else(|| {
+            syntax_error(
+                edge.line,
+                format!("Missing weight of edge {} -> {}", edge.from, edge.to),
+            )
+        })?;
+        graph.add_edge(&from, &to, parse_weight(weight, edge.line)?)?;
+    }
+
+    Ok(graph)
+}
+
+/// Build the vertex of a DOT id, add it unless it is already in the graph, and return its id
+fn add_vertex_once<G: GraphMut + ?Sized, E: Display>(
+    graph: &mut G,
+    ids: &mut HashMap<String, VertexId<G>>,
+    name: &str,
+    line: usize,
+    vertex: &impl Fn(&str) -> Result<G::Vertex, E>,
+) -> Result<VertexId<G>, DotError<VertexId<G>>> {
+    if let Some(id) = ids.get(name) {
+        return Ok(id.clone());
+    }
+
+    let vertex = vertex(name)
+        .map_err(|error| syntax_error(line, format!("Invalid vertex {}: {}", name, error)))?;
+    let id = vertex.get_id().clone();
+    graph.add_vertex(vertex)?;
+    ids.insert(name.to_string(), id.clone());
+
+    Ok(id)
+}
+
+/// Parse the weight of an edge
+fn parse_weight<W: FromStr, Id>(weight: &str, line: usize) -> Result<W, DotError<Id>> {
+    weight
+        .parse()
+        .map_err(|_| syntax_error(line, format!("Invalid weight {}", weight)))
+}
+
+/// Return the DOT id of a value: the value quoted, with its quotes escaped
+fn quote(value: &impl Display) -> String {
+    let value = value.to_string().replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{}\"", value)
+}
+
+fn syntax_error<Id>(line: usize, message: String) -> DotError<Id> {
+    DotError::Syntax { line, message }
+}
+
+/// Statements of a DOT graph needed to build a graph
+#[derive(Debug, Default)]
+struct Statements {
+    /// Denote if the graph is strict (no duplicate edges)
+    strict: bool,
+
+    /// Vertex names with their line, in the order of the input
+    vertices: Vec<(String, usize)>,
+
+    /// Edges, in the order of the input
+    edges: Vec<Edge>,
+}
+
+/// An edge statement of a DOT graph
+#[derive(Debug)]
+struct Edge {
+    from: String,
+    to: String,
+    attributes: HashMap<String, String>,
+    line: usize,
+}
+
+/// Keywords of the DOT language (they are case insensitive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Keyword {
+    Strict,
+    Graph,
+    Digraph,
+    Node,
+    Edge,
+    Subgraph,
+}
+
+impl Keyword {
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            ("strict", Keyword::Strict),
+            ("graph", Keyword::Graph),
+            ("digraph", Keyword::Digraph),
+            ("node", Keyword::Node),
+            ("edge", Keyword::Edge),
+            ("subgraph", Keyword::Subgraph),
+        ]
+        .into_iter()
+        .find(|(keyword_name, _)| keyword_name.eq_ignore_ascii_case(name))
+        .map(|(_, keyword)| keyword)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    /// An identifier, a number, or a quoted string (without its quotes)
+    Id(String),
+    Keyword(Keyword),
+    Arrow,
+    LeftBrace,
+    RightBrace,
+    LeftBracket,
+    RightBracket,
+    Equal,
+    Semicolon,
+    Comma,
+}
+
+/// Split the input in tokens, each one with its line
+fn tokenize<Id>(input: &str) -> Result<Vec<(Token, usize)>, DotError<Id>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    let mut line = 1;
+    // Denote if only whitespaces were found since the start of the line
+    let mut line_start = true;
+
+    while let Some(char) = chars.next() {
+        let token = match char {
+            '\n' => {
+                line += 1;
+                line_start = true;
+                continue;
+            }
+            char if char.is_whitespace() => continue,
+            // Preprocessor output lines are ignored
+            '#' if line_start => {
+                while chars.next_if(|char| *char != '\n').is_some() {}
+                continue;
+            }
+            '/' if chars.next_if_eq(&'/').is_some() => {
+                while chars.next_if(|char| *char != '\n').is_some() {}
+                continue;
+            }
+            '/' if chars.next_if_eq(&'*').is_some() => {
+                let comment_line = line;
+                let mut previous = ' ';
+                loop {
+                    match chars.next() {
+                        Some('/') if previous == '*' => break,
+                        Some(char) => {
+                            if char == '\n' {
+                                line += 1;
+                            }
+                            previous = char;
+                        }
+                        None => {
+                            return Err(syntax_error(
+                                comment_line,
+                                "Unterminated comment".to_string(),
+                            ))
+                        }
+                    }
+                }
+                continue;
+            }
+            '{' => Token::LeftBrace,
+            '}' => Token::RightBrace,
+            '[' => Token::LeftBracket,
+            ']' => Token::RightBracket,
+            '=' => Token::Equal,
+            ';' => Token::Semicolon,
+            ',' => Token::Comma,
+            '-' if chars.next_if_eq(&'>').is_some() => Token::Arrow,
+            '-' if chars.next_if_eq(&'-').is_some() => {
+                return Err(syntax_error(
+                    line,
+                    "Undirected edges are not supported".to_string(),
+                ))
+            }
+            '"' => {
+                let string_line = line;
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next_if(|char| *char == '"' || *char == '\\') {
+                            Some(escaped) => value.push(escaped),
+                            None => value.push('\\'),
+                        },
+                        Some(char) => {
+                            if char == '\n' {
+                                line += 1;
+                            }
+                            value.push(char);
+                        }
+                        None => {
+                            return Err(syntax_error(
+                                string_line,
+                                "Unterminated string".to_string(),
+                            ))
+                        }
+                    }
+                }
+                Token::Id(value)
+            }
+            char if is_id_char(char) || char == '-' => {
+                let mut name = char.to_string();
+                while let Some(char) = chars.next_if(|char| is_id_char(*char)) {
+                    name.push(char);
+                }
+                match Keyword::from_name(&name) {
+                    Some(keyword) => Token::Keyword(keyword),
+                    None => Token::Id(name),
+                }
+            }
+            char => return Err(syntax_error(line, format!("Unexpected character {}", char))),
+        };
+
+        tokens.push((token, line));
+        line_start = false;
+    }
+
+    Ok(tokens)
+}
+
+/// Denote if a character can be part of an unquoted id
+fn is_id_char(char: char) -> bool {
+    char.is_alphanumeric() || char == '_' || char == '.'
+}
+
+/// Parse the statements of a DOT graph
+fn parse<Id>(input: &str) -> Result<Statements, DotError<Id>> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        position: 0,
+    };
+
+    // Header: [strict] digraph [name] {
+    let mut statements = Statements {
+        strict: parser.next_if(&Token::Keyword(Keyword::Strict)),
+        ..Statements::default()
+    };
+    match parser.next() {
+        Some(Token::Keyword(Keyword::Digraph)) => {}
+        Some(Token::Keyword(Keyword::Graph)) => {
+            return Err(parser.error("Undirected graphs are not supported"))
+        }
+        _ => return Err(parser.error("Expected digraph")),
+    }
+    if let Some(Token::Id(_)) = parser.peek() {
+        parser.next();
+    }
+    parser.expect(Token::LeftBrace)?;
+
+    loop {
+        match parser.peek() {
+            Some(Token::RightBrace) => break,
+            Some(Token::Semicolon) => {
+                parser.next();
+            }
+            Some(_) => parser.parse_statement(&mut statements)?,
+            None => return Err(parser.error("Expected }")),
+        }
+    }
+    parser.expect(Token::RightBrace)?;
+
+    match parser.peek() {
+        Some(_) => Err(parser.error("Unexpected content after the graph")),
+        None => Ok(statements),
+    }
+}
+
+/// Recursive descent parser over the tokens of a DOT graph
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        self.position += 1;
+        token
+    }
+
+    /// Consume the next token if it is the given one, and denote if it was
+    fn next_if(&mut self, token: &Token) -> bool {
+        let found = self.peek() == Some(token);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    /// Consume the next token, or Err if it is not the given one
+    fn expect<Id>(&mut self, token: Token) -> Result<(), DotError<Id>> {
+        match self.next_if(&token) {
+            true => Ok(()),
+            false => Err(self.error(&format!("Expected {:?}", token))),
+        }
+    }
+
+    /// Consume the next token if it is an id, and return it, or Err
+    fn expect_id<Id>(&mut self) -> Result<String, DotError<Id>> {
+        match self.peek() {
+            Some(Token::Id(name)) => {
+                let name = name.clone();
+                self.position += 1;
+                Ok(name)
+            }
+            _ => Err(self.error("Expected an id")),
+        }
+    }
+
+    /// Return a syntax error at the line of the current token
+    fn error<Id>(&self, message: &str) -> DotError<Id> {
+        let line = self
+            .tokens
+            .get(self.position.min(self.tokens.len().saturating_sub(1)))
+            .map_or(1, |(_, line)| *line);
+
+        syntax_error(line, message.to_string())
+    }
+
+    /// Parse a statement, and add its vertices and edges to the statements
+    fn parse_statement<Id>(&mut self, statements: &mut Statements) -> Result<(), DotError<Id>> {
+        match self.peek() {
+            // Default attributes are ignored
+            Some(Token::Keyword(Keyword::Graph | Keyword::Node | Keyword::Edge)) => {
+                self.next();
+                self.parse_attributes()?;
+                Ok(())
+            }
+            Some(Token::Keyword(Keyword::Subgraph) | Token::LeftBrace) => {
+                Err(self.error("Subgraphs are not supported"))
+            }
+            Some(Token::Id(_)) => {
+                let line = self.tokens[self.position].1;
+                let name = self.expect_id()?;
+
+                // Graph attributes are ignored
+                if self.next_if(&Token::Equal) {
+                    self.expect_id()?;
+                    return Ok(());
+                }
+
+                let mut chain = vec![name];
+                while self.next_if(&Token::Arrow) {
+                    chain.push(self.expect_id()?);
+                }
+                let attributes = self.parse_attributes()?;
+
+                match chain.len() {
+                    1 => statements.vertices.push((chain.pop().unwrap(), line)),
+                    _ => statements.edges.extend(chain.windows(2).map(|edge| Edge {
+                        from: edge[0].clone(),
+                        to: edge[1].clone(),
+                        attributes: attributes.clone(),
+                        line,
+                    })),
+                }
+
+                Ok(())
+            }
+            _ => Err(self.error("Unexpected token")),
+        }
+    }
+
+    /// Parse the attribute lists following a statement: [key=value, ...] [...]
+    fn parse_attributes<Id>(&mut self) -> Result<HashMap<String, String>, DotError<Id>> {
+        let mut attributes = HashMap::new();
+
+        while self.next_if(&Token::LeftBracket) {
+            while !self.next_if(&Token::RightBracket) {
+                let key = self.expect_id()?;
+                let value = match self.next_if(&Token::Equal) {
+                    true => self.expect_id()?,
+                    false => "true".to_string(),
+                };
+                attributes.insert(key, value);
+
+                if !self.next_if(&Token::Comma) {
+                    self.next_if(&Token::Semicolon);
+                }
+            }
+        }
+
+        Ok(attributes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::{city, City};
+    use super::*;
+
+    /// Cities linked by flights, with their duration
+    const CITIES: &str = include_str!("fixtures/cities.dot");
+
+    #[test]
+    fn test_parse_weighted_graph() -> Result<(), DotError<String>> {
+        let graph: OrientedWeightedGraph<String> =
+            OrientedWeightedGraph::from_dot(CITIES, String::from_str)?;
+
+        assert_eq!(graph.vertex_count(), 5);
+        assert_eq!(graph.edge_count(), 6);
+        // Chained edges share their attributes
+        assert_eq!(
+            graph.edge(&"Paris".to_string(), &"Lyon".to_string()),
+            Some(&2)
+        );
+        assert_eq!(
+            graph.edge(&"Lyon".to_string(), &"Marseille".to_string()),
+            Some(&2)
+        );
+        // Isolated vertex
+        assert!(graph.vertex_exists(&"Sydney".to_string()));
+
+        let path = graph
+            .shortest_path(&"Tokio".to_string(), &"Marseille".to_string())?
+            .unwrap();
+        assert_eq!(path.total_weight, 17);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), DotError<String>> {
+        let graph: OrientedWeightedGraph<String> =
+            OrientedWeightedGraph::from_dot(CITIES, String::from_str)?;
+        let dot = graph.to_dot();
+
+        assert_eq!(
+            dot,
+            "digraph {
+    \"Lyon\";
+    \"Marseille\";
+    \"Paris\";
+    \"Sydney\";
+    \"Tokio\";
+    \"Lyon\" -> \"Marseille\" [label=\"2\"];
+    \"Paris\" -> \"Lyon\" [label=\"2\"];
+    \"Paris\" -> \"Marseille\" [label=\"8\"];
+    \"Paris\" -> \"Tokio\" [label=\"14\"];
+    \"Tokio\" -> \"Marseille\" [label=\"20\"];
+    \"Tokio\" -> \"Paris\" [label=\"13\"];
+}
+"
+        );
+
+        // The export can be parsed back
+        let parsed: OrientedWeightedGraph<String> =
+            OrientedWeightedGraph::from_dot(&dot, String::from_str)?;
+        assert_eq!(parsed.to_dot(), dot);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_highlighted_path() -> Result<(), DotError<String>> {
+        let graph: OrientedWeightedGraph<String> =
+            OrientedWeightedGraph::from_dot(CITIES, String::from_str)?;
+        let path = graph
+            .shortest_path(&"Tokio".to_string(), &"Marseille".to_string())?
+            .unwrap();
+
+        let dot = graph.to_dot_highlighting(&path.vertices);
+        assert!(dot.contains("    \"Tokio\" [color=red];\n"));
+        assert!(dot.contains("    \"Sydney\";\n"));
+        assert!(dot.contains("    \"Tokio\" -> \"Paris\" [label=\"13\", color=red, penwidth=2];\n"));
+        assert!(dot.contains("    \"Paris\" -> \"Tokio\" [label=\"14\"];\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oriented_graph() -> Result<(), DotError<u32>> {
+        let input = "
+            /* Numeric ids, unweighted edges */
+            strict digraph tasks {
+                rankdir = LR
+                node [shape=box]
+                1 -> 2 -> 3; 1 -> \"3\" [style=dashed]
+            }
+        ";
+        let graph: OrientedGraph<u32> = OrientedGraph::from_dot(input, u32::from_str)?;
+
+        assert_eq!(graph.vertex_count(), 3);
+        assert_eq!(graph.topological_order(), Ok(vec![1, 2, 3]));
+        assert_eq!(
+            graph.to_dot_highlighting(&[1, 3]),
+            "digraph {
+    \"1\" [color=red];
+    \"2\";
+    \"3\" [color=red];
+    \"1\" -> \"2\";
+    \"1\" -> \"3\" [color=red, penwidth=2];
+    \"2\" -> \"3\";
+}
+"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quoted_ids() -> Result<(), DotError<String>> {
+        // Quotes are escaped
+        let mut graph: OrientedGraph<String> = OrientedGraph::new();
+        graph.add_vertex("The \"Big\" Apple".to_string())?;
+        let dot = graph.to_dot();
+        assert_eq!(dot, "digraph {\n    \"The \\\"Big\\\" Apple\";\n}\n");
+        assert_eq!(
+            OrientedGraph::from_dot(&dot, String::from_str)?.vertices,
+            graph.vertices
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors() {
+        let parse =
+            |input: &str| OrientedWeightedGraph::<String>::from_dot(input, String::from_str).err();
+
+        assert_eq!(
+            parse("graph { a -- b }"),
+            Some(DotError::Syntax {
+                line: 1,
+                message: "Undirected edges are not supported".to_string()
+            })
+        );
+        assert_eq!(
+            parse("digraph {\n a -> b [label=3]\n b -> c\n}"),
+            Some(DotError::Syntax {
+                line: 3,
+                message: "Missing weight of edge b -> c".to_string()
+            })
+        );
+        assert_eq!(
+            parse("digraph { a -> b [label=far] }").map(|error| error.to_string()),
+            Some("Line 1: Invalid weight far".to_string())
+        );
+        assert_eq!(
+            parse("digraph { a -> b [label=1]; a -> b [label=2] }"),
+            Some(DotError::Graph(GraphError::EdgeAlreadyExists {
+                from: "a".to_string(),
+                to: "b".to_string()
+            }))
+        );
+        assert!(matches!(
+            parse("digraph { subgraph { a } }"),
+            Some(DotError::Syntax { .. })
+        ));
+        assert!(matches!(
+            parse("digraph { a -> b [label=1]"),
+            Some(DotError::Syntax { .. })
+        ));
+    }
+
+    #[test]
+    fn test_strict_graph() -> Result<(), DotError<String>> {
+        // The statements of the same edge are merged, the last weight wins
+        let graph: OrientedWeightedGraph<String> = OrientedWeightedGraph::from_dot(
+            "strict digraph { a -> b [label=1]; a -> b [label=2]; a -> b; b -> a [label=3] }",
+            String::from_str,
+        )?;
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.edge(&"a".to_string(), &"b".to_string()), Some(&2));
+        assert_eq!(graph.edge(&"b".to_string(), &"a".to_string()), Some(&3));
+
+        let graph: OrientedGraph<String> =
+            OrientedGraph::from_dot("strict digraph { a -> b -> a -> b }", String::from_str)?;
+        assert_eq!(graph.edge_count(), 2);
+
+        Ok(())
+    }
+
+    /// Build a city from a capitalized name
+    fn capitalized_city(name: &str) -> Result<City, String> {
+        match name.starts_with(char::is_uppercase) {
+            true => Ok(city(name)),
+            false => Err(format!("{} is not capitalized", name)),
+        }
+    }
+
+    #[test]
+    fn test_vertex_constructor() -> Result<(), DotError<String>> {
+        let graph: OrientedWeightedGraph<City> =
+            OrientedWeightedGraph::from_dot(CITIES, capitalized_city)?;
+
+        assert_eq!(graph.vertex_count(), 5);
+        assert_eq!(graph.vertex(&"Sydney".to_string()), Some(&city("Sydney")));
+        assert_eq!(
+            graph.edge(&"Paris".to_string(), &"Lyon".to_string()),
+            Some(&2)
+        );
+
+        // The errors of the constructor are syntax errors
+        assert_eq!(
+            OrientedGraph::from_dot("digraph {\n Paris\n Paris -> lyon\n}", capitalized_city).err(),
+            Some(DotError::Syntax {
+                line: 3,
+                message: "Invalid vertex lyon: lyon is not capitalized".to_string()
+            })
+        );
+
+        Ok(())
+    }
+}
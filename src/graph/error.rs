@@ -0,0 +1,184 @@
+use std::{error::Error, fmt};
+
+/// Errors returned by the fallible graph operations
+///
+/// Each variant carries the ids of the vertices involved, so callers
+/// can branch on the kind of failure without parsing a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphError<Id> {
+    /// A vertex with the same id is already in the graph
+    VertexAlreadyExists(Id),
+
+    /// The vertex is not in the graph
+    VertexNotFound(Id),
+
+    /// An edge between the two vertices is already in the graph
+    EdgeAlreadyExists { from: Id, to: Id },
+
+    /// There is no edge between the two vertices
+    EdgeNotFound { from: Id, to: Id },
+
+    /// The target vertex can not be reached from the source vertex
+    NoPath { from: Id, to: Id },
+
+    /// The edge between the two vertices has a negative weight, which
+    /// the algorithm does not support
+    NegativeWeight { from: Id, to: Id },
+
+    /// A sum of weights does not fit in the weight type, so the result
+    /// can not be computed
+    ///
+    /// It carries the edge whose weight made the sum overflow
+    WeightOverflow { from: Id, to: Id },
+
+    /// The graph contains a cycle whose total weight is negative,
+    /// so shortest paths are not defined
+    ///
+    /// It carries the vertices of the cycle, in the order of its edges
+    NegativeCycle(Vec<Id>),
+
+    /// The graph contains a cycle, so its vertices can not be ordered
+    ///
+    /// It carries the vertices of the cycle, in the order of its edges
+    Cycle(Vec<Id>),
+
+    /// The graph contains a cycle with an odd number of vertices,
+    /// so it is not bipartite
+    ///
+    /// It carries the vertices of the cycle, in the order of its edges
+    OddCycle(Vec<Id>),
+}
+
+impl<Id: fmt::Display> fmt::Display for GraphError<Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GraphError::VertexAlreadyExists(id) => write!(f, "Vertex {} already exists", id),
+            GraphError::VertexNotFound(id) => write!(f, "Vertex {} does not exist", id),
+            GraphError::EdgeAlreadyExists { from, to } => {
+                write!(
+                    f,
+                    "Edge from vertex {} to vertex {} already exists",
+                    from, to
+                )
+            }
+            GraphError::EdgeNotFound { from, to } => {
+                write!(
+                    f,
+                    "Edge from vertex {} to vertex {} does not exist",
+                    from, to
+                )
+            }
+            GraphError::NoPath { from, to } => {
+                write!(f, "No path from vertex {} to vertex {}", from, to)
+            }
+            GraphError::NegativeWeight { from, to } => {
+                write!(
+                    f,
+                    "Edge from vertex {} to vertex {} has a negative weight",
+                    from, to
+                )
+            }
+            GraphError::WeightOverflow { from, to } => {
+                write!(
+                    f,
+                    "Sum of weights overflows at the edge from vertex {} to vertex {}",
+                    from, to
+                )
+            }
+            GraphError::NegativeCycle(cycle) => {
+                write!(f, "Negative cycle through vertices")?;
+                write_cycle(f, cycle)
+            }
+            GraphError::Cycle(cycle) => {
+                write!(f, "Cycle through vertices")?;
+                write_cycle(f, cycle)
+            }
+            GraphError::OddCycle(cycle) => {
+                write!(f, "Odd cycle through vertices")?;
+                write_cycle(f, cycle)
+            }
+        }
+    }
+}
+
+/// Write the vertices of a cycle, separated by arrows
+fn write_cycle<Id: fmt::Display>(f: &mut fmt::Formatter<'_>, cycle: &[Id]) -> fmt::Result {
+    for (index, id) in cycle.iter().enumerate() {
+        match index {
+            0 => write!(f, " {}", id)?,
+            _ => write!(f, " -> {}", id)?,
+        }
+    }
+    Ok(())
+}
+
+impl<Id: fmt::Debug + fmt::Display> Error for GraphError<Id> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            GraphError::VertexAlreadyExists("Tokio".to_string()).to_string(),
+            "Vertex Tokio already exists"
+        );
+        assert_eq!(
+            GraphError::VertexNotFound("Tokio".to_string()).to_string(),
+            "Vertex Tokio does not exist"
+        );
+        assert_eq!(
+            GraphError::EdgeAlreadyExists {
+                from: "Tokio".to_string(),
+                to: "Paris".to_string()
+            }
+            .to_string(),
+            "Edge from vertex Tokio to vertex Paris already exists"
+        );
+        assert_eq!(
+            GraphError::EdgeNotFound {
+                from: "Tokio".to_string(),
+                to: "Paris".to_string()
+            }
+            .to_string(),
+            "Edge from vertex Tokio to vertex Paris does not exist"
+        );
+        assert_eq!(
+            GraphError::NoPath {
+                from: "Tokio".to_string(),
+                to: "Paris".to_string()
+            }
+            .to_string(),
+            "No path from vertex Tokio to vertex Paris"
+        );
+        assert_eq!(
+            GraphError::NegativeWeight {
+                from: "Tokio".to_string(),
+                to: "Paris".to_string()
+            }
+            .to_string(),
+            "Edge from vertex Tokio to vertex Paris has a negative weight"
+        );
+        assert_eq!(
+            GraphError::WeightOverflow {
+                from: "Tokio".to_string(),
+                to: "Paris".to_string()
+            }
+            .to_string(),
+            "Sum of weights overflows at the edge from vertex Tokio to vertex Paris"
+        );
+        assert_eq!(
+            GraphError::NegativeCycle(vec!["Tokio".to_string(), "Paris".to_string()]).to_string(),
+            "Negative cycle through vertices Tokio -> Paris"
+        );
+        assert_eq!(
+            GraphError::Cycle(vec!["Tokio".to_string(), "Paris".to_string()]).to_string(),
+            "Cycle through vertices Tokio -> Paris"
+        );
+        assert_eq!(
+            GraphError::OddCycle(vec!["Tokio".to_string()]).to_string(),
+            "Odd cycle through vertices Tokio"
+        );
+    }
+}
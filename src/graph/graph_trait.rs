@@ -0,0 +1,469 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::bipartite::{self, MatchingResult, TwoColoringResult};
+use super::depth_first_search::{self, DfsTimestamps, DfsVisitor};
+use super::error::GraphError;
+use super::identifiable::Identifiable;
+use super::traversal::{self, GraphVisit};
+
+/// Type of the ids of the vertices of a graph
+pub type VertexId<G> = <<G as Graph>::Vertex as Identifiable>::Id;
+
+/// Common interface of the graph representations
+///
+/// Graph implementations only differ by the way they store their edges,
+/// so this trait exposes the basic read operations on vertices and edges.
+/// Traversals and analysis algorithms are then written once against
+/// this trait and work with any representation.
+/// The graphs that can be modified also implement `GraphMut`.
+///
+/// Edges carry a `Weight`, which is `()` for unweighted graphs.
+pub trait Graph {
+    /// Type of the vertices stored in the graph
+    type Vertex: Identifiable;
+
+    /// Type of the value carried by the edges
+    type Weight;
+
+    /// Return the vertex with the given id, if any
+    fn vertex(&self, id: &VertexId<Self>) -> Option<&Self::Vertex>;
+
+    /// Iterate over the vertices of the graph
+    fn vertices(&self) -> impl Iterator<Item = &Self::Vertex>;
+
+    /// Iterate over the ids of the vertices of the graph
+    fn vertex_ids(&self) -> impl Iterator<Item = &VertexId<Self>>;
+
+    /// Iterate over the edges going out of a vertex, as (neighbor id, weight) pairs,
+    /// and Err if the vertex does not exist
+    #[allow(clippy::type_complexity)]
+    fn neighbors<'a>(
+        &'a self,
+        id: &VertexId<Self>,
+    ) -> Result<
+        impl Iterator<Item = (&'a VertexId<Self>, &'a Self::Weight)> + use<'a, Self>,
+        GraphError<VertexId<Self>>,
+    >;
+
+    /// Return the weight of the edge between two vertices, if any
+    fn edge(&self, from: &VertexId<Self>, to: &VertexId<Self>) -> Option<&Self::Weight>;
+
+    /// Return the number of vertices in the graph
+    fn vertex_count(&self) -> usize;
+
+    /// Return the number of edges in the graph
+    fn edge_count(&self) -> usize;
+
+    /// Denote if a vertex exists
+    fn vertex_exists(&self, id: &VertexId<Self>) -> bool {
+        self.vertex(id).is_some()
+    }
+
+    /// Check if a vertex exists and return Ok, or Err it does not exists
+    fn vertex_exists_or_err(&self, id: &VertexId<Self>) -> Result<(), GraphError<VertexId<Self>>> {
+        if self.vertex_exists(id) {
+            Ok(())
+        } else {
+            Err(GraphError::VertexNotFound(id.clone()))
+        }
+    }
+
+    /// Denote if an edge exists
+    fn edge_exists(&self, from: &VertexId<Self>, to: &VertexId<Self>) -> bool {
+        self.edge(from, to).is_some()
+    }
+
+    /// Traverse the graph depth fist starting from the vertex specified in parameter
+    ///
+    /// Note: this version is implemented using the call stack (recursive)
+    fn traverse_depth_first_recursive(
+        &self,
+        start_vertex_id: &VertexId<Self>,
+    ) -> Result<Vec<VertexId<Self>>, GraphError<VertexId<Self>>> {
+        let mut result = Vec::new();
+        let mut seen = HashSet::new();
+
+        internal_traverse_depth_first_recursive(self, start_vertex_id, &mut result, &mut seen)?;
+
+        Ok(result)
+    }
+
+    /// Traverse the graph depth fist starting from the vertex specified in parameter
+    ///
+    /// Note: this version is implemented using an explicit stack and a loop (iterative)
+    fn traverse_depth_first_iterative(
+        &self,
+        start_vertex_id: &VertexId<Self>,
+    ) -> Result<Vec<VertexId<Self>>, GraphError<VertexId<Self>>> {
+        let mut result = Vec::new();
+        let mut seen = HashSet::new();
+        let mut stack = Vec::new();
+
+        // Init the stack with the starting node
+        stack.push(start_vertex_id.clone());
+
+        while let Some(vertex_id) = stack.pop() {
+            // A vertex can be pushed several times before being visited,
+            // only keep the first visit
+            if seen.contains(&vertex_id) {
+                continue;
+            }
+
+            // Check that vertex is valid
+            self.vertex_exists_or_err(&vertex_id)?;
+
+            // Add vertex to result
+            result.push(vertex_id.clone());
+
+            // Mark vertex as seen
+            seen.insert(vertex_id.clone());
+
+            // Push neighbors vertices to the stack
+            for (neighbor_id, _) in self.neighbors(&vertex_id)? {
+                if !seen.contains(neighbor_id) {
+                    stack.push(neighbor_id.clone());
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Traverse the graph breadth fist starting from the vertex specified in parameter
+    ///
+    /// Note: this version is implemented using an explicit queue and a loop (iterative)
+    fn traverse_breadth_first(
+        &self,
+        start_vertex_id: &VertexId<Self>,
+    ) -> Result<Vec<VertexId<Self>>, GraphError<VertexId<Self>>> {
+        let mut result = Vec::new();
+        let mut seen = HashSet::new();
+        let mut queue = VecDeque::new();
+
+        // Check that starting vertex is valid
+        self.vertex_exists_or_err(start_vertex_id)?;
+
+        // Init the queue with the starting node
+        // Note: vertices are marked as seen when queued, so they are
+        // queued only once
+        seen.insert(start_vertex_id.clone());
+        queue.push_back(start_vertex_id.clone());
+
+        while let Some(vertex_id) = queue.pop_front() {
+            // Push vertex unseen neighbors to the queue
+            for (neighbor_id, _) in self.neighbors(&vertex_id)? {
+                if seen.insert(neighbor_id.clone()) {
+                    queue.push_back(neighbor_id.clone());
+                }
+            }
+
+            // Add vertex to result
+            result.push(vertex_id);
+        }
+
+        Ok(result)
+    }
+
+    /// Iterate lazily over the vertices reachable from a start vertex, breadth first,
+    /// with their depth and parent, or Err if the start vertex does not exist
+    ///
+    /// See `traversal::bfs`
+    fn bfs<'a>(
+        &'a self,
+        start_vertex_id: &VertexId<Self>,
+    ) -> Result<
+        impl Iterator<Item = GraphVisit<'a, Self>> + use<'a, Self>,
+        GraphError<VertexId<Self>>,
+    > {
+        traversal::bfs(self, start_vertex_id)
+    }
+
+    /// Iterate lazily over the vertices reachable from a start vertex, depth first,
+    /// with their depth and parent, or Err if the start vertex does not exist
+    ///
+    /// See `traversal::dfs`
+    fn dfs<'a>(
+        &'a self,
+        start_vertex_id: &VertexId<Self>,
+    ) -> Result<
+        impl Iterator<Item = GraphVisit<'a, Self>> + use<'a, Self>,
+        GraphError<VertexId<Self>>,
+    > {
+        traversal::dfs(self, start_vertex_id)
+    }
+
+    /// Search the whole graph depth first, sending the events (vertex discovery
+    /// and finish, classified edges) to a visitor, and return the timestamps
+    ///
+    /// See `depth_first_search::depth_first_search`
+    fn depth_first_search<V: DfsVisitor<VertexId<Self>>>(
+        &self,
+        visitor: &mut V,
+    ) -> DfsTimestamps<VertexId<Self>> {
+        depth_first_search::depth_first_search(self, visitor)
+    }
+
+    /// Search the graph depth first from a start vertex, sending the events to
+    /// a visitor, and return the timestamps, or Err if the start vertex does not exist
+    ///
+    /// See `depth_first_search::depth_first_search_from`
+    fn depth_first_search_from<V: DfsVisitor<VertexId<Self>>>(
+        &self,
+        start_vertex_id: &VertexId<Self>,
+        visitor: &mut V,
+    ) -> Result<DfsTimestamps<VertexId<Self>>, GraphError<VertexId<Self>>> {
+        depth_first_search::depth_first_search_from(self, start_vertex_id, visitor)
+    }
+
+    /// Return a split of the vertices in two sides such that every edge links
+    /// a vertex of each side, or an `OddCycle` error if there is none
+    ///
+    /// See `bipartite::is_bipartite`
+    fn is_bipartite(&self) -> TwoColoringResult<Self> {
+        bipartite::is_bipartite(self)
+    }
+
+    /// Return the largest set of edges of a bipartite graph such that no
+    /// vertex is touched by two of them, or an `OddCycle` error if it is not bipartite
+    ///
+    /// See `bipartite::hopcroft_karp`
+    fn hopcroft_karp(&self) -> MatchingResult<Self> {
+        bipartite::hopcroft_karp(self)
+    }
+}
+
+/// Operations of the graph representations that can be modified
+pub trait GraphMut: Graph {
+    /// Add a vertex into the graph, and Err if it already exists
+    fn add_vertex(&mut self, vertex: Self::Vertex) -> Result<(), GraphError<VertexId<Self>>>;
+
+    /// Add an edge into the graph, and Err if it already exists, of if it targets invalid vertices
+    fn add_edge(
+        &mut self,
+        from: &VertexId<Self>,
+        to: &VertexId<Self>,
+        weight: Self::Weight,
+    ) -> Result<(), GraphError<VertexId<Self>>>;
+
+    /// Try to remove an edge and return an Err if it was not present
+    fn remove_edge(
+        &mut self,
+        from: &VertexId<Self>,
+        to: &VertexId<Self>,
+    ) -> Result<(), GraphError<VertexId<Self>>>;
+
+    /// Try to remove a vertex and its edges, and return an Err if it was not present
+    fn remove_vertex(&mut self, id: &VertexId<Self>) -> Result<(), GraphError<VertexId<Self>>>;
+}
+
+/// Recursive part of `Graph::traverse_depth_first_recursive`
+fn internal_traverse_depth_first_recursive<G: Graph + ?Sized>(
+    graph: &G,
+    vertex_id: &VertexId<G>,
+    result: &mut Vec<VertexId<G>>,
+    seen: &mut HashSet<VertexId<G>>,
+) -> Result<(), GraphError<VertexId<G>>> {
+    // Check for invalid vertex
+    graph.vertex_exists_or_err(vertex_id)?;
+
+    // Add vertex to results
+    result.push(vertex_id.clone());
+
+    // Note vertex as visited
+    seen.insert(vertex_id.clone());
+
+    // Recursively traverse neighbors
+    for (neighbor_id, _) in graph.neighbors(vertex_id)? {
+        // if neighbor has not been visited yet
+        if !seen.contains(neighbor_id) {
+            internal_traverse_depth_first_recursive(graph, neighbor_id, result, seen)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Return the neighbors of a vertex taken from the graph
+///
+/// Note: the vertex is in the graph, so `neighbors` can not Err
+pub(crate) fn graph_neighbors<'a, G: Graph + ?Sized>(
+    graph: &'a G,
+    vertex_id: &VertexId<G>,
+) -> impl Iterator<Item = (&'a VertexId<G>, &'a G::Weight)> + use<'a, G> {
+    match graph.neighbors(vertex_id) {
+        Ok(neighbors) => neighbors,
+        Err(_) => unreachable!("the vertex comes from the graph"),
+    }
+}
+
+/// Return the neighbors of each vertex of the graph, ignoring the orientation
+/// of the edges
+///
+/// Note: two edges A -> B and B -> A are merged into a single edge A - B
+pub(crate) fn undirected_neighbors<G: Graph + ?Sized>(
+    graph: &G,
+) -> HashMap<&VertexId<G>, Vec<&VertexId<G>>> {
+    let mut neighbors: HashMap<_, Vec<_>> = graph.vertex_ids().map(|id| (id, Vec::new())).collect();
+
+    for vertex_id in graph.vertex_ids() {
+        for (neighbor_id, _) in graph_neighbors(graph, vertex_id) {
+            neighbors.get_mut(vertex_id).unwrap().push(neighbor_id);
+
+            // The other direction is only added if it is not an edge itself
+            if graph.edge(neighbor_id, vertex_id).is_none() {
+                neighbors.get_mut(neighbor_id).unwrap().push(vertex_id);
+            }
+        }
+    }
+
+    neighbors
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::oriented_graph::OrientedGraph;
+    use super::super::oriented_weighted_graph::OrientedWeightedGraph;
+    use super::super::test_utils::{add_cities, City};
+    use super::*;
+
+    /// Build the graph a -> b, a -> c, b -> d, c -> d
+    fn build_diamond<G: GraphMut<Vertex = City>>(
+        graph: &mut G,
+        weight: impl Fn() -> G::Weight,
+    ) -> Result<(), GraphError<String>>
+    where
+        G::Weight: Clone,
+    {
+        add_cities(
+            graph,
+            &["a", "b", "c", "d"],
+            &[
+                ("a", "b", weight()),
+                ("a", "c", weight()),
+                ("b", "d", weight()),
+                ("c", "d", weight()),
+            ],
+        )
+    }
+
+    /// Check the trait behavior, whatever the graph representation
+    fn check_graph<G: GraphMut<Vertex = City>>(
+        mut graph: G,
+        weight: impl Fn() -> G::Weight,
+    ) -> Result<(), GraphError<String>>
+    where
+        G::Weight: Clone,
+    {
+        build_diamond(&mut graph, weight)?;
+
+        // Vertices and edges lookups
+        assert_eq!(graph.vertex_count(), 4);
+        assert_eq!(graph.edge_count(), 4);
+        assert_eq!(graph.vertex(&"a".to_string()).unwrap().name, "a");
+        assert!(graph.vertex(&"Montreal".to_string()).is_none());
+        assert!(graph.edge_exists(&"a".to_string(), &"b".to_string()));
+        assert!(!graph.edge_exists(&"b".to_string(), &"a".to_string()));
+        assert_eq!(graph.neighbors(&"a".to_string())?.count(), 2);
+        assert_eq!(
+            graph.neighbors(&"Montreal".to_string()).err(),
+            Some(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        // Traversals visit each reachable vertex once
+        for result in [
+            graph.traverse_breadth_first(&"a".to_string())?,
+            graph.traverse_depth_first_iterative(&"a".to_string())?,
+            graph.traverse_depth_first_recursive(&"a".to_string())?,
+        ] {
+            assert_eq!(result.len(), 4);
+            assert_eq!(result[0], "a");
+        }
+        assert_eq!(graph.traverse_breadth_first(&"a".to_string())?[3], "d");
+        assert_eq!(
+            graph.traverse_breadth_first(&"b".to_string())?,
+            vec!["b".to_string(), "d".to_string()]
+        );
+
+        // Removals
+        graph.remove_edge(&"a".to_string(), &"b".to_string())?;
+        assert_eq!(graph.edge_count(), 3);
+        assert_eq!(
+            graph.remove_edge(&"a".to_string(), &"b".to_string()),
+            Err(GraphError::EdgeNotFound {
+                from: "a".to_string(),
+                to: "b".to_string()
+            })
+        );
+        graph.remove_vertex(&"d".to_string())?;
+        assert_eq!(graph.vertex_count(), 3);
+        assert_eq!(graph.edge_count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oriented_graph() -> Result<(), GraphError<String>> {
+        check_graph(OrientedGraph::new(), || ())
+    }
+
+    #[test]
+    fn test_oriented_weighted_graph() -> Result<(), GraphError<String>> {
+        check_graph(OrientedWeightedGraph::new(), || 1)
+    }
+
+    #[test]
+    fn test_traversals_visit_vertices_once() -> Result<(), GraphError<String>> {
+        let mut graph = OrientedGraph::new();
+        build_diamond(&mut graph, || ())?;
+        graph.add_edge(&"c".to_string(), &"b".to_string())?;
+        let sorted = |mut ids: Vec<String>| {
+            ids.sort();
+            ids
+        };
+        let all_ids = vec!["a", "b", "c", "d"];
+
+        // d is reached from b and from c: it is queued once, as vertices
+        // are marked when queued (it used to be visited twice)
+        let result = graph.traverse_breadth_first(&"a".to_string())?;
+        assert_eq!(result[3], "d");
+        assert_eq!(sorted(result), all_ids);
+
+        // b can be pushed from a and from c before being visited: only its
+        // first pop is a visit (it used to be visited twice)
+        let result = graph.traverse_depth_first_iterative(&"a".to_string())?;
+        assert_eq!(result[0], "a");
+        assert_eq!(sorted(result), all_ids);
+
+        Ok(())
+    }
+
+    // Vertex identified by an integer, for testing purpose
+    #[derive(PartialEq, Clone, Debug)]
+    struct Router {
+        pub id: u64,
+    }
+
+    impl Identifiable for Router {
+        type Id = u64;
+
+        fn get_id(&self) -> &u64 {
+            &self.id
+        }
+    }
+
+    #[test]
+    fn test_integer_ids() -> Result<(), GraphError<u64>> {
+        let mut graph: OrientedGraph<Router> = OrientedGraph::new();
+
+        for id in 0..3 {
+            graph.add_vertex(Router { id })?;
+        }
+        graph.add_edge(&0, &1)?;
+        graph.add_edge(&1, &2)?;
+
+        assert_eq!(graph.traverse_breadth_first(&0)?, vec![0, 1, 2]);
+        assert_eq!(graph.add_edge(&0, &3), Err(GraphError::VertexNotFound(3)));
+
+        Ok(())
+    }
+}
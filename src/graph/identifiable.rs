@@ -1,3 +1,118 @@
+use std::hash::Hash;
+
+/// Trait implemented by the vertices stored in a graph
+///
+/// The id is used as key to store the vertex, and to refer to it in the edges.
+///
+/// It is implemented for the common types that can be used directly as vertices:
+/// - `String`, `&str` and integers are their own id
+/// - `(Id, Data)` tuples are identified by their first element
 pub trait Identifiable {
-    fn get_id(&self) -> String;
+    /// Type of the vertex id
+    type Id: Hash + Eq + Clone;
+
+    fn get_id(&self) -> &Self::Id;
+}
+
+impl Identifiable for String {
+    type Id = String;
+
+    fn get_id(&self) -> &String {
+        self
+    }
+}
+
+impl<'a> Identifiable for &'a str {
+    type Id = &'a str;
+
+    fn get_id(&self) -> &&'a str {
+        self
+    }
+}
+
+/// Implement `Identifiable` for integer types, which are their own id
+macro_rules! impl_identifiable_for_integers {
+    ($($integer:ty),*) => {
+        $(
+            impl Identifiable for $integer {
+                type Id = $integer;
+
+                fn get_id(&self) -> &$integer {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_identifiable_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<Id: Hash + Eq + Clone, Data> Identifiable for (Id, Data) {
+    type Id = Id;
+
+    fn get_id(&self) -> &Id {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::error::GraphError;
+    use super::super::graph_trait::{Graph, GraphMut};
+    use super::super::oriented_graph::OrientedGraph;
+    use super::super::oriented_weighted_graph::OrientedWeightedGraph;
+    use super::*;
+
+    #[test]
+    fn test_get_id() {
+        assert_eq!("Tokio".to_string().get_id(), "Tokio");
+        assert_eq!("Tokio".get_id(), &"Tokio");
+        assert_eq!(42u64.get_id(), &42);
+        assert_eq!((7, "Tokio").get_id(), &7);
+    }
+
+    #[test]
+    fn test_string_and_str_vertices() -> Result<(), GraphError<&'static str>> {
+        let mut graph: OrientedGraph<&str> = OrientedGraph::new();
+
+        graph.add_vertex("Tokio")?;
+        graph.add_vertex("Paris")?;
+        graph.add_edge(&"Tokio", &"Paris")?;
+
+        assert_eq!(
+            graph.traverse_breadth_first(&"Tokio")?,
+            vec!["Tokio", "Paris"]
+        );
+
+        let mut graph: OrientedGraph<String> = OrientedGraph::new();
+        graph.add_vertex("Tokio".to_string()).unwrap();
+
+        assert_eq!(
+            graph.vertex(&"Tokio".to_string()),
+            Some(&"Tokio".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tuple_vertices() -> Result<(), GraphError<u32>> {
+        let mut graph: OrientedWeightedGraph<(u32, &str)> = OrientedWeightedGraph::new();
+
+        graph.add_vertex((1, "Tokio"))?;
+        graph.add_vertex((2, "Paris"))?;
+        graph.add_edge(&1, &2, 10)?;
+
+        assert_eq!(graph.vertex(&2), Some(&(2, "Paris")));
+        assert_eq!(
+            graph.add_vertex((1, "Vancouver")),
+            Err(GraphError::VertexAlreadyExists(1))
+        );
+        assert_eq!(
+            graph.shortest_path(&1, &2)?.map(|path| path.vertices),
+            Some(vec![1, 2])
+        );
+
+        Ok(())
+    }
 }
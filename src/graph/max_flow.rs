@@ -0,0 +1,572 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::Sub;
+
+use super::error::GraphError;
+use super::graph_trait::{Graph, VertexId};
+use super::identifiable::Identifiable;
+use super::weight::Weight;
+
+/// Maximum flow from a source vertex to a sink vertex, where the edge
+/// weights are the capacities of the edges
+#[derive(Debug, Clone)]
+pub struct MaxFlow<Id, W = u32> {
+    /// Total flow going out of the source (and into the sink)
+    pub value: W,
+
+    /// Flow going through each edge of the graph
+    pub edge_flows: HashMap<(Id, Id), W>,
+
+    /// Edges of a minimum cut: removing them disconnects the sink from
+    /// the source, and their total capacity is the value of the flow
+    pub min_cut: Vec<(Id, Id)>,
+}
+
+impl<Id: std::hash::Hash + Eq + Clone, W: Weight> MaxFlow<Id, W> {
+    /// Return the flow going through an edge (0 if there is no edge)
+    pub fn flow(&self, from: &Id, to: &Id) -> W {
+        self.edge_flows
+            .get(&(from.clone(), to.clone()))
+            .copied()
+            .unwrap_or(W::zero())
+    }
+}
+
+/// Result of the maximum flow algorithms
+pub type MaxFlowResult<G> =
+    Result<MaxFlow<VertexId<G>, <G as Graph>::Weight>, GraphError<VertexId<G>>>;
+
+/// Return the maximum flow that can go from a source vertex to a sink vertex
+///
+/// This function uses [Edmonds-Karp algorithm](https://en.wikipedia.org/wiki/Edmonds%E2%80%93Karp_algorithm):
+/// while a path with free capacity from the source to the sink can be found
+/// (breadth first, so it is the one with the least edges), as much flow as
+/// possible is sent through it. It runs in O(V * E^2).
+///
+/// Note: weights are capacities, so it Errs on negative weights
+pub fn edmonds_karp<G: Graph>(
+    graph: &G,
+    source_id: &VertexId<G>,
+    sink_id: &VertexId<G>,
+) -> MaxFlowResult<G>
+where
+    G::Weight: Weight + Sub<Output = G::Weight>,
+{
+    let (mut network, source, sink) = FlowNetwork::new(graph, source_id, sink_id)?;
+
+    if source != sink {
+        // Send flow through the shortest path with free capacity, until there is none
+        while let Some(path) = network.shortest_augmenting_path(source, sink) {
+            let bottleneck = path
+                .iter()
+                .map(|&edge| network.edges[edge].residual_capacity)
+                .min()
+                .unwrap();
+
+            for edge in path {
+                network.push_flow(edge, bottleneck)?;
+            }
+        }
+    }
+
+    network.into_max_flow(source)
+}
+
+/// Return the maximum flow that can go from a source vertex to a sink vertex
+///
+/// This function uses [Dinic's algorithm](https://en.wikipedia.org/wiki/Dinic%27s_algorithm):
+/// the vertices are leveled by their distance from the source, then flow
+/// is sent through paths going from a level to the next one, until they are
+/// all saturated. Levels are then updated, until the sink can not be reached.
+/// It runs in O(V^2 * E), and is usually faster than Edmonds-Karp algorithm.
+///
+/// Note: weights are capacities, so it Errs on negative weights
+pub fn dinic<G: Graph>(
+    graph: &G,
+    source_id: &VertexId<G>,
+    sink_id: &VertexId<G>,
+) -> MaxFlowResult<G>
+where
+    G::Weight: Weight + Sub<Output = G::Weight>,
+{
+    let (mut network, source, sink) = FlowNetwork::new(graph, source_id, sink_id)?;
+
+    if source != sink {
+        while let Some(levels) = network.levels(source, sink) {
+            // Index of the next edge to try from each vertex: the edges
+            // already tried can not carry more flow until levels are updated
+            let mut next_edges = vec![0; network.adjacency.len()];
+
+            while network.push_blocking_flow(source, sink, &levels, &mut next_edges)?
+                > G::Weight::zero()
+            {}
+        }
+    }
+
+    network.into_max_flow(source)
+}
+
+/// Edge of the residual network
+#[derive(Debug, Clone, Copy)]
+struct FlowEdge<W> {
+    from: usize,
+    to: usize,
+
+    /// Capacity of the edge in the graph (zero for reverse edges)
+    capacity: W,
+
+    /// Capacity left to send flow through the edge
+    residual_capacity: W,
+}
+
+/// Residual network: the graph edges, with for each one a reverse
+/// edge allowing to cancel the flow sent through it
+///
+/// The vertices are identified by dense indices, and the edges are stored
+/// by pairs: the edge `2 * k` is an edge of the graph, and `2 * k + 1` its
+/// reverse edge (so the pair of an edge is `edge ^ 1`).
+struct FlowNetwork<'a, Id, W> {
+    /// Vertex ids, by index
+    ids: Vec<&'a Id>,
+
+    /// Edges of the network
+    edges: Vec<FlowEdge<W>>,
+
+    /// Edges going out of each vertex
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl<'a, Id: std::hash::Hash + Eq + Clone, W: Weight + Sub<Output = W>> FlowNetwork<'a, Id, W> {
+    /// Build the residual network of a graph, and return it along with the
+    /// indices of the source and the sink, or Err if they are not in the graph
+    /// or if a capacity is negative
+    fn new<G: Graph<Weight = W>>(
+        graph: &'a G,
+        source_id: &Id,
+        sink_id: &Id,
+    ) -> Result<(Self, usize, usize), GraphError<Id>>
+    where
+        G::Vertex: Identifiable<Id = Id>,
+    {
+        // Check that source and sink exist, or err
+        graph.vertex_exists_or_err(source_id)?;
+        graph.vertex_exists_or_err(sink_id)?;
+
+        let ids: Vec<&Id> = graph.vertex_ids().collect();
+        let indices: HashMap<&Id, usize> = ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (*id, index))
+            .collect();
+
+        let mut network = Self {
+            adjacency: vec![Vec::new(); ids.len()],
+            edges: Vec::new(),
+            ids,
+        };
+
+        for (from, from_id) in network.ids.clone().into_iter().enumerate() {
+            for (to_id, capacity) in graph.neighbors(from_id)? {
+                let to = indices[to_id];
+
+                if *capacity < W::zero() {
+                    return Err(GraphError::NegativeWeight {
+                        from: from_id.clone(),
+                        to: to_id.clone(),
+                    });
+                }
+
+                // A self loop can not carry any flow
+                if from != to {
+                    network.add_edge(from, to, *capacity);
+                }
+            }
+        }
+
+        Ok((network, indices[source_id], indices[sink_id]))
+    }
+
+    /// Add an edge and its reverse edge
+    fn add_edge(&mut self, from: usize, to: usize, capacity: W) {
+        self.adjacency[from].push(self.edges.len());
+        self.edges.push(FlowEdge {
+            from,
+            to,
+            capacity,
+            residual_capacity: capacity,
+        });
+
+        self.adjacency[to].push(self.edges.len());
+        self.edges.push(FlowEdge {
+            from: to,
+            to: from,
+            capacity: W::zero(),
+            residual_capacity: W::zero(),
+        });
+    }
+
+    /// Send flow through an edge, which frees the same capacity on its reverse edge
+    fn push_flow(&mut self, edge: usize, flow: W) -> Result<(), GraphError<Id>> {
+        self.edges[edge].residual_capacity = self.edges[edge].residual_capacity - flow;
+        self.edges[edge ^ 1].residual_capacity =
+            self.add_to_edge(edge ^ 1, self.edges[edge ^ 1].residual_capacity, flow)?;
+
+        Ok(())
+    }
+
+    /// Add a flow to a value related to an edge, or Err if it overflows
+    fn add_to_edge(&self, edge: usize, value: W, flow: W) -> Result<W, GraphError<Id>> {
+        let FlowEdge { from, to, .. } = self.edges[edge];
+
+        value
+            .checked_add(flow)
+            .ok_or_else(|| GraphError::WeightOverflow {
+                from: self.ids[from].clone(),
+                to: self.ids[to].clone(),
+            })
+    }
+
+    /// Return the distance from the source of each vertex, through the edges
+    /// with free capacity (None if it can not be reached)
+    fn distances_from(&self, source: usize) -> Vec<Option<usize>> {
+        let mut distances = vec![None; self.adjacency.len()];
+        let mut queue = VecDeque::from([source]);
+        distances[source] = Some(0);
+
+        while let Some(vertex) = queue.pop_front() {
+            for &edge in &self.adjacency[vertex] {
+                let FlowEdge {
+                    to,
+                    residual_capacity,
+                    ..
+                } = self.edges[edge];
+
+                if residual_capacity > W::zero() && distances[to].is_none() {
+                    distances[to] = distances[vertex].map(|distance| distance + 1);
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Return the edges of the shortest path with free capacity
+    /// from the source to the sink, or None if there is none
+    fn shortest_augmenting_path(&self, source: usize, sink: usize) -> Option<Vec<usize>> {
+        // Edge used to reach each vertex
+        let mut parent_edges: Vec<Option<usize>> = vec![None; self.adjacency.len()];
+        let mut queue = VecDeque::from([source]);
+
+        while let Some(vertex) = queue.pop_front() {
+            for &edge in &self.adjacency[vertex] {
+                let FlowEdge {
+                    to,
+                    residual_capacity,
+                    ..
+                } = self.edges[edge];
+
+                if residual_capacity > W::zero() && to != source && parent_edges[to].is_none() {
+                    parent_edges[to] = Some(edge);
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        // Build the path by walking back from the sink
+        let mut path = Vec::new();
+        let mut vertex = sink;
+        while vertex != source {
+            let edge = parent_edges[vertex]?;
+            path.push(edge);
+            vertex = self.edges[edge].from;
+        }
+
+        Some(path)
+    }
+
+    /// Return the levels of the vertices (their distance from the source),
+    /// or None if the sink can not be reached anymore
+    fn levels(&self, source: usize, sink: usize) -> Option<Vec<Option<usize>>> {
+        let levels = self.distances_from(source);
+
+        levels[sink].map(|_| levels)
+    }
+
+    /// Send flow through a path going from a level to the next one, from
+    /// the source to the sink, and return the sent flow (zero if there is none)
+    ///
+    /// The path is searched depth first, with an explicit stack so that long
+    /// paths can not overflow the call stack.
+    fn push_blocking_flow(
+        &mut self,
+        source: usize,
+        sink: usize,
+        levels: &[Option<usize>],
+        next_edges: &mut [usize],
+    ) -> Result<W, GraphError<Id>> {
+        // Edges of the path from the source to the current vertex
+        let mut path = Vec::new();
+        let mut vertex = source;
+
+        while vertex != sink {
+            match self.next_level_edge(vertex, levels, next_edges) {
+                // Go deeper, through the edge
+                Some(edge) => {
+                    path.push(edge);
+                    vertex = self.edges[edge].to;
+                }
+                // The vertex is a dead end: go back, and skip the edge leading to it
+                None => {
+                    let Some(edge) = path.pop() else {
+                        return Ok(W::zero());
+                    };
+                    vertex = self.edges[edge].from;
+                    next_edges[vertex] += 1;
+                }
+            }
+        }
+
+        // Send as much flow as the path can carry
+        let flow = path
+            .iter()
+            .map(|&edge| self.edges[edge].residual_capacity)
+            .min()
+            .unwrap();
+        for edge in path {
+            self.push_flow(edge, flow)?;
+        }
+
+        Ok(flow)
+    }
+
+    /// Return the next edge with free capacity going from a vertex to
+    /// the next level, or None if there is none
+    ///
+    /// The edges that can not be used are skipped for good, as they can
+    /// not carry more flow until levels are updated
+    fn next_level_edge(
+        &self,
+        vertex: usize,
+        levels: &[Option<usize>],
+        next_edges: &mut [usize],
+    ) -> Option<usize> {
+        while let Some(&edge) = self.adjacency[vertex].get(next_edges[vertex]) {
+            let FlowEdge {
+                to,
+                residual_capacity,
+                ..
+            } = self.edges[edge];
+
+            if residual_capacity > W::zero() && levels[to] == levels[vertex].map(|level| level + 1)
+            {
+                return Some(edge);
+            }
+
+            next_edges[vertex] += 1;
+        }
+
+        None
+    }
+
+    /// Build the result from the network, once the flow is maximum,
+    /// or Err if the value of the flow overflows
+    fn into_max_flow(self, source: usize) -> Result<MaxFlow<Id, W>, GraphError<Id>> {
+        // The flow through an edge is the capacity it used
+        let mut edge_flows = HashMap::new();
+        let mut source_out_flow = W::zero();
+        let mut source_in_flow = W::zero();
+
+        for (index, edge) in self.edges.iter().enumerate().step_by(2) {
+            let flow = edge.capacity - edge.residual_capacity;
+            edge_flows.insert(
+                (self.ids[edge.from].clone(), self.ids[edge.to].clone()),
+                flow,
+            );
+
+            if edge.from == source {
+                source_out_flow = self.add_to_edge(index, source_out_flow, flow)?;
+            }
+            if edge.to == source {
+                source_in_flow = self.add_to_edge(index, source_in_flow, flow)?;
+            }
+        }
+
+        // The vertices that can still be reached from the source are on the
+        // source side of the cut, the saturated edges leaving them are the cut
+        let distances = self.distances_from(source);
+        let source_side: HashSet<usize> = (0..self.ids.len())
+            .filter(|&vertex| distances[vertex].is_some())
+            .collect();
+
+        let min_cut = self
+            .edges
+            .iter()
+            .step_by(2)
+            .filter(|edge| source_side.contains(&edge.from) && !source_side.contains(&edge.to))
+            .map(|edge| (self.ids[edge.from].clone(), self.ids[edge.to].clone()))
+            .collect();
+
+        Ok(MaxFlow {
+            value: source_out_flow - source_in_flow,
+            edge_flows,
+            min_cut,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::graph_trait::GraphMut;
+    use super::super::oriented_weighted_graph::OrientedWeightedGraph;
+    use super::super::test_utils::build_weighted_graph_from_edges;
+    use super::*;
+
+    /// Check that the flow is valid: it respects the capacities, and what
+    /// goes in a vertex goes out of it (except for the source and the sink)
+    fn check_flow(
+        graph: &OrientedWeightedGraph<&'static str>,
+        max_flow: &MaxFlow<&'static str>,
+        source_id: &'static str,
+        sink_id: &'static str,
+    ) {
+        let mut balances: HashMap<&str, i64> = HashMap::new();
+
+        for ((from, to), flow) in &max_flow.edge_flows {
+            assert!(*flow <= *graph.edge(from, to).unwrap());
+            *balances.entry(from).or_default() -= *flow as i64;
+            *balances.entry(to).or_default() += *flow as i64;
+        }
+
+        for (id, balance) in balances {
+            match id {
+                id if id == source_id => assert_eq!(balance, -(max_flow.value as i64)),
+                id if id == sink_id => assert_eq!(balance, max_flow.value as i64),
+                _ => assert_eq!(balance, 0),
+            }
+        }
+
+        // The capacity of the min cut is the value of the flow
+        let cut_capacity: u32 = max_flow
+            .min_cut
+            .iter()
+            .map(|(from, to)| graph.edge(from, to).unwrap())
+            .sum();
+        assert_eq!(cut_capacity, max_flow.value);
+    }
+
+    #[test]
+    fn test_max_flow() -> Result<(), GraphError<&'static str>> {
+        let graph = build_weighted_graph_from_edges(&[
+            ("s", "a", 10),
+            ("s", "c", 10),
+            ("a", "b", 4),
+            ("a", "c", 2),
+            ("a", "d", 8),
+            ("c", "d", 9),
+            ("d", "b", 6),
+            ("b", "t", 10),
+            ("d", "t", 10),
+        ])?;
+
+        for max_flow in [graph.edmonds_karp(&"s", &"t")?, graph.dinic(&"s", &"t")?] {
+            assert_eq!(max_flow.value, 19);
+            assert_eq!(max_flow.edge_flows.len(), graph.edge_count());
+            // The edges of the min cut are saturated by any maximum flow
+            assert_eq!(max_flow.flow(&"s", &"a"), 10);
+            assert_eq!(max_flow.flow(&"c", &"d"), 9);
+            assert_eq!(max_flow.flow(&"t", &"b"), 0);
+            check_flow(&graph, &max_flow, "s", "t");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_cut() -> Result<(), GraphError<&'static str>> {
+        // The edges a -> b and c -> d are the bottleneck
+        let graph = build_weighted_graph_from_edges(&[
+            ("s", "a", 5),
+            ("s", "c", 5),
+            ("a", "b", 2),
+            ("c", "d", 1),
+            ("a", "c", 3),
+            ("b", "t", 5),
+            ("d", "t", 5),
+        ])?;
+
+        for max_flow in [graph.edmonds_karp(&"s", &"t")?, graph.dinic(&"s", &"t")?] {
+            let mut min_cut = max_flow.min_cut.clone();
+            min_cut.sort();
+
+            assert_eq!(max_flow.value, 3);
+            assert_eq!(min_cut, vec![("a", "b"), ("c", "d")]);
+            check_flow(&graph, &max_flow, "s", "t");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_flow() -> Result<(), GraphError<&'static str>> {
+        let graph = build_weighted_graph_from_edges(&[("s", "a", 5), ("t", "a", 5)])?;
+
+        let max_flow = graph.dinic(&"s", &"t")?;
+        assert_eq!(max_flow.value, 0);
+        assert_eq!(max_flow.min_cut, vec![]);
+
+        // Unknown vertices should Err
+        assert_eq!(
+            graph.edmonds_karp(&"s", &"z").err(),
+            Some(GraphError::VertexNotFound("z"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flow_overflow() -> Result<(), GraphError<&'static str>> {
+        // Each path fits in the weight type, but not the total flow
+        let graph = build_weighted_graph_from_edges(&[
+            ("s", "t", 200u8),
+            ("s", "a", 100),
+            ("a", "t", 100),
+        ])?;
+
+        for max_flow in [graph.edmonds_karp(&"s", &"t"), graph.dinic(&"s", &"t")] {
+            assert!(matches!(max_flow, Err(GraphError::WeightOverflow { .. })));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_capacity() -> Result<(), GraphError<&'static str>> {
+        let graph = build_weighted_graph_from_edges(&[("s", "a", 5), ("a", "t", -5)])?;
+
+        for max_flow in [graph.edmonds_karp(&"s", &"t"), graph.dinic(&"s", &"t")] {
+            assert_eq!(
+                max_flow.err(),
+                Some(GraphError::NegativeWeight { from: "a", to: "t" })
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_long_path() -> Result<(), GraphError<u32>> {
+        // A single path through many vertices
+        let vertex_count: u32 = 100_000;
+        let mut graph = OrientedWeightedGraph::new();
+        for vertex in 0..vertex_count {
+            graph.add_vertex(vertex)?;
+            if vertex > 0 {
+                graph.add_edge(&(vertex - 1), &vertex, 1 + vertex % 7)?;
+            }
+        }
+
+        assert_eq!(graph.dinic(&0, &(vertex_count - 1))?.value, 1);
+
+        Ok(())
+    }
+}
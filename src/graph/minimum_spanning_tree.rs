@@ -0,0 +1,249 @@
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use super::error::GraphError;
+use super::graph_trait::{graph_neighbors, Graph, VertexId};
+use super::shortest_path::VertexByDistance;
+use super::weight::Weight;
+use crate::union_find::KeyedUnionFind;
+
+/// Edges linking all the vertices of a graph with the smallest total weight
+///
+/// When the graph is not connected, the vertices can not all be linked:
+/// it is then a forest, made of one tree by connected part of the graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningForest<Id, W = u32> {
+    /// Edges of the forest, as (from, to, weight)
+    pub edges: Vec<(Id, Id, W)>,
+
+    /// Sum of the weights of the edges
+    pub total_weight: W,
+
+    /// Number of trees of the forest (1 if the graph is connected)
+    pub tree_count: usize,
+}
+
+/// Result of the minimum spanning tree algorithms
+pub type SpanningForestResult<G> =
+    Result<SpanningForest<VertexId<G>, <G as Graph>::Weight>, GraphError<VertexId<G>>>;
+
+/// Return the minimum spanning forest of the graph
+///
+/// This function uses [Kruskal's algorithm](https://en.wikipedia.org/wiki/Kruskal%27s_algorithm):
+/// edges are added by increasing weight, unless they link two vertices
+/// that are already linked by the forest.
+/// It Errs with `WeightOverflow` if the total weight does not fit in the weight type.
+///
+/// Note: the orientation of the edges is ignored
+pub fn kruskal<G: Graph>(graph: &G) -> SpanningForestResult<G>
+where
+    G::Weight: Weight,
+{
+    // Sort all the edges by weight
+    let mut edges: Vec<_> = graph
+        .vertex_ids()
+        .flat_map(|vertex_id| {
+            graph_neighbors(graph, vertex_id)
+                .map(move |(neighbor_id, weight)| (vertex_id, neighbor_id, *weight))
+        })
+        .collect();
+    edges.sort_by_key(|(_, _, weight)| *weight);
+
+    // Keep track of the vertices already linked by the forest
+    let mut linked_vertices = KeyedUnionFind::new();
+
+    let mut forest_edges = Vec::new();
+    let mut total_weight = G::Weight::zero();
+
+    for (from, to, weight) in edges {
+        // An edge between linked vertices would make a cycle
+        if linked_vertices.union(from, to) {
+            total_weight = add_edge_weight(total_weight, from, to, weight)?;
+            forest_edges.push((from.clone(), to.clone(), weight));
+        }
+    }
+
+    // Each edge merges two trees
+    Ok(SpanningForest {
+        tree_count: graph.vertex_count() - forest_edges.len(),
+        edges: forest_edges,
+        total_weight,
+    })
+}
+
+/// Return the minimum spanning forest of the graph
+///
+/// This function uses [Prim's algorithm](https://en.wikipedia.org/wiki/Prim%27s_algorithm):
+/// a tree grows from a vertex, by adding the lightest edge linking it to a
+/// new vertex, until no new vertex can be reached. A new tree is then grown
+/// from a vertex out of the forest, if any.
+/// It Errs with `WeightOverflow` if the total weight does not fit in the weight type.
+///
+/// Note: the orientation of the edges is ignored
+pub fn prim<G: Graph>(graph: &G) -> SpanningForestResult<G>
+where
+    G::Weight: Weight,
+{
+    // Walk the edges in both directions
+    let mut edges: HashMap<_, Vec<_>> = HashMap::new();
+    for vertex_id in graph.vertex_ids() {
+        for (neighbor_id, weight) in graph_neighbors(graph, vertex_id) {
+            edges
+                .entry(vertex_id)
+                .or_default()
+                .push((neighbor_id, *weight));
+            edges
+                .entry(neighbor_id)
+                .or_default()
+                .push((vertex_id, *weight));
+        }
+    }
+
+    // Keep track of the vertices already in the forest
+    let mut seen_vertices = HashSet::new();
+
+    let mut forest_edges = Vec::new();
+    let mut total_weight = G::Weight::zero();
+    let mut tree_count = 0;
+
+    for start_id in graph.vertex_ids() {
+        if !seen_vertices.insert(start_id) {
+            continue;
+        }
+        tree_count += 1;
+
+        // The priority queue always gives the lightest edge leaving the tree
+        let mut priority_queue = BinaryHeap::new();
+        let mut current_id = start_id;
+
+        loop {
+            // Queue the edges from the last vertex added to the tree
+            for (neighbor_id, weight) in edges.get(current_id).into_iter().flatten() {
+                if !seen_vertices.contains(neighbor_id) {
+                    priority_queue.push(VertexByDistance {
+                        id: (current_id, *neighbor_id),
+                        distance: *weight,
+                    });
+                }
+            }
+
+            // Find the lightest edge to a new vertex, or stop growing the tree
+            let mut lightest_edge = None;
+            while let Some(edge) = priority_queue.pop() {
+                if !seen_vertices.contains(edge.id.1) {
+                    lightest_edge = Some(edge);
+                    break;
+                }
+            }
+            let Some(VertexByDistance {
+                id: (from, to),
+                distance: weight,
+            }) = lightest_edge
+            else {
+                break;
+            };
+
+            seen_vertices.insert(to);
+            total_weight = add_edge_weight(total_weight, from, to, weight)?;
+            forest_edges.push((from.clone(), to.clone(), weight));
+            current_id = to;
+        }
+    }
+
+    Ok(SpanningForest {
+        edges: forest_edges,
+        total_weight,
+        tree_count,
+    })
+}
+
+/// Add the weight of an edge to the total weight of the forest,
+/// or Err if it overflows
+fn add_edge_weight<Id: Clone, W: Weight>(
+    total_weight: W,
+    from: &Id,
+    to: &Id,
+    weight: W,
+) -> Result<W, GraphError<Id>> {
+    total_weight
+        .checked_add(weight)
+        .ok_or_else(|| GraphError::WeightOverflow {
+            from: from.clone(),
+            to: to.clone(),
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::build_weighted_graph;
+    use super::*;
+
+    /// Return the edges of the forest, sorted and without orientation
+    fn sorted_edges(forest: &SpanningForest<&'static str>) -> Vec<(&'static str, &'static str)> {
+        let mut edges: Vec<_> = forest
+            .edges
+            .iter()
+            .map(|(from, to, _)| (*from.min(to), *from.max(to)))
+            .collect();
+        edges.sort();
+        edges
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() -> Result<(), GraphError<&'static str>> {
+        let graph = build_weighted_graph(
+            &["a", "b", "c", "d", "e"],
+            &[
+                ("a", "b", 4),
+                ("a", "c", 1),
+                ("c", "b", 2),
+                ("b", "d", 5),
+                ("c", "d", 8),
+                ("d", "e", 3),
+                // The orientation is ignored, so this edge is the same than d -> e
+                ("e", "d", 7),
+            ],
+        )?;
+
+        for forest in [graph.kruskal()?, graph.prim()?] {
+            assert_eq!(forest.total_weight, 11);
+            assert_eq!(forest.tree_count, 1);
+            assert_eq!(
+                sorted_edges(&forest),
+                vec![("a", "c"), ("b", "c"), ("b", "d"), ("d", "e")]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_minimum_spanning_forest() -> Result<(), GraphError<&'static str>> {
+        // Two connected parts, and a vertex alone
+        let graph = build_weighted_graph(
+            &["a", "b", "c", "d", "e", "f"],
+            &[("a", "b", 1), ("b", "c", 2), ("c", "a", 3), ("d", "e", 4)],
+        )?;
+
+        for forest in [graph.kruskal()?, graph.prim()?] {
+            assert_eq!(forest.total_weight, 7);
+            assert_eq!(forest.tree_count, 3);
+            assert_eq!(
+                sorted_edges(&forest),
+                vec![("a", "b"), ("b", "c"), ("d", "e")]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_weight_overflow() -> Result<(), GraphError<&'static str>> {
+        let graph = build_weighted_graph(&["a", "b", "c"], &[("a", "b", 200u8), ("b", "c", 100)])?;
+
+        for forest in [graph.kruskal(), graph.prim()] {
+            assert!(matches!(forest, Err(GraphError::WeightOverflow { .. })));
+        }
+
+        Ok(())
+    }
+}
@@ -10,7 +10,40 @@
 //! pathfinding, network flow, and resource allocation. Their ability to represent
 //! intricate relationships and dependencies makes them a fundamental tool for
 //! solving real-world problems across diverse domains.
+//!
+//! The graph representations share the `Graph` trait, which reads vertices
+//! and edges and runs the algorithms. Adding and removing vertices or edges
+//! is done through the `GraphMut` trait, which must be imported as well:
+//! `use graph::{Graph, GraphMut};`
 
-mod identifiable;
+pub mod a_star;
+pub mod adjacency_matrix_graph;
+pub mod all_pairs_shortest_paths;
+pub mod arena_graph;
+pub mod bellman_ford;
+pub mod biconnected_components;
+pub mod bipartite;
+pub mod csr_graph;
+pub mod depth_first_search;
+pub mod dot;
+pub mod error;
+pub mod graph_trait;
+pub mod identifiable;
+pub mod max_flow;
+pub mod minimum_spanning_tree;
 pub mod oriented_graph;
 pub mod oriented_weighted_graph;
+pub mod shortest_path;
+pub mod strongly_connected_components;
+#[cfg(test)]
+mod test_utils;
+pub mod topological_sort;
+pub mod traversal;
+pub mod undirected_graph;
+pub mod undirected_weighted_graph;
+pub mod weight;
+
+pub use error::GraphError;
+pub use graph_trait::{Graph, GraphMut};
+pub use identifiable::Identifiable;
+pub use weight::{OrderedF64, Weight};
@@ -1,11 +1,18 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
+use super::biconnected_components::{self, Biconnectivity};
+use super::dot::{self, DotError};
+use super::error::GraphError;
+use super::graph_trait::{Graph, GraphMut};
 use super::identifiable::Identifiable;
+use super::strongly_connected_components::{self, StronglyConnectedComponents};
+use super::topological_sort;
 
 /// A basic oriented graph implementation
 ///
 /// It is 'naive' in the sens that it plays around references and lifetime rust safety
-/// by using String id as pointers for the vertices hashmap keys, and cloned string
+/// by using vertex ids as pointers for the vertices hashmap keys, and cloned ids
 /// to refer the vertices in the edges.
 /// It skips all the safety rust can bring, forces to do runtime check and to be really careful
 /// when cleaning vertices/edges in order to keep the graph correct.
@@ -13,10 +20,13 @@ use super::identifiable::Identifiable;
 /// To benefit of rust safety, more advanced memory management methods should be used
 /// (see [here](https://stackoverflow.com/questions/34747464/implement-graph-like-data-structure-in-rust)
 /// and [here](https://github.com/nrc/r4cppp/blob/master/graphs/README.md) for more infos)
+///
+/// `ArenaGraph` is such an alternative: it refers to the vertices and edges
+/// with generation-checked handles instead of ids.
 #[derive(Debug)]
 pub struct OrientedGraph<T: Identifiable> {
     /// List of the graph vertices
-    pub vertices: HashMap<String, T>,
+    pub vertices: HashMap<T::Id, T>,
 
     /// List of the graph edges
     ///
@@ -24,7 +34,7 @@ pub struct OrientedGraph<T: Identifiable> {
     /// Example: For vertices A and B, you can express:
     /// - A -> B by storing the entry { A: [B] }
     /// - B -> A by storing the entry { B: [A] }
-    pub edges: HashMap<String, HashSet<String>>,
+    pub edges: HashMap<T::Id, HashSet<T::Id>>,
 }
 
 impl<T: Identifiable> OrientedGraph<T> {
@@ -36,225 +46,257 @@ impl<T: Identifiable> OrientedGraph<T> {
         }
     }
 
-    /// Add a vertex into the graph, and Err if it already exists
-    pub fn add_vertex(&mut self, vertex: T) -> Result<(), String> {
-        let vertex_id = vertex.get_id();
-
-        // Err if vertex already exists
-        // Or insert the vertex in the graph
-        if self.vertex_exists(&vertex_id) {
-            Err("Vertex ".to_owned() + &vertex_id + " already exists")
-        } else {
-            self.vertices.insert(vertex_id.clone(), vertex);
-            self.edges.insert(vertex_id, HashSet::new());
-            Ok(())
-        }
-    }
-
     /// Add an edge into the graph, and Err if it already exists, of if it targets invalid vertices
-    pub fn add_edge(&mut self, vertex1_id: String, vertex2_id: String) -> Result<(), String> {
+    pub fn add_edge(
+        &mut self,
+        vertex1_id: &T::Id,
+        vertex2_id: &T::Id,
+    ) -> Result<(), GraphError<T::Id>> {
         // Check that vertices exist, or err
-        self.vertex_exists_or_err(&vertex1_id)?;
-        self.vertex_exists_or_err(&vertex2_id)?;
+        self.vertex_exists_or_err(vertex1_id)?;
+        self.vertex_exists_or_err(vertex2_id)?;
 
         // Insert the new edge
         let result = self
             .edges
-            .get_mut(&vertex1_id)
+            .get_mut(vertex1_id)
             .unwrap()
             .insert(vertex2_id.clone());
 
         match result {
             true => Ok(()),
-            false => Err("Edge between from vertex ".to_owned()
-                + &vertex1_id
-                + " to vertex "
-                + &vertex2_id
-                + " already exists"),
+            false => Err(GraphError::EdgeAlreadyExists {
+                from: vertex1_id.clone(),
+                to: vertex2_id.clone(),
+            }),
         }
     }
 
-    /// Try to remove an edge and return an Err if it was not present
-    pub fn remove_edge(&mut self, vertex1_id: String, vertex2_id: String) -> Result<(), String> {
-        // Check that vertices exist, or err
-        self.vertex_exists_or_err(&vertex1_id)?;
-        self.vertex_exists_or_err(&vertex2_id)?;
+    /// Return the vertex ids ordered so that every edge goes from a vertex
+    /// to a vertex placed after it, or Err if the graph contains a cycle
+    ///
+    /// See `topological_sort::topological_order`
+    pub fn topological_order(&self) -> Result<Vec<T::Id>, GraphError<T::Id>>
+    where
+        T::Id: Ord,
+    {
+        topological_sort::topological_order(self)
+    }
 
-        // Try to remove the value
-        let result = self
-            .edges
-            .get_mut(&vertex1_id)
-            .map(|ids| ids.remove(&vertex2_id));
+    /// Denote if the graph contains a cycle
+    ///
+    /// See `topological_sort::has_cycle`
+    pub fn has_cycle(&self) -> bool {
+        topological_sort::has_cycle(self)
+    }
 
-        // If the edge was present and removed, return Ok, else Err
-        if result == Some(true) {
-            Ok(())
-        } else {
-            Err("Edge from vertex ".to_owned()
-                + &vertex1_id
-                + " to vertex "
-                + &vertex2_id
-                + " does not exist")
-        }
+    /// Return the vertices of a cycle of the graph, or None if it has no cycle
+    ///
+    /// See `topological_sort::find_cycle`
+    pub fn find_cycle(&self) -> Option<Vec<T::Id>> {
+        topological_sort::find_cycle(self)
     }
 
-    /// Try to remove a vertex and return an Err if it was not present
-    pub fn remove_vertex(&mut self, vertex_id: String) -> Result<(), String> {
-        // Check that vertex exist, or err
-        self.vertex_exists_or_err(&vertex_id)?;
+    /// Return the strongly connected components of the graph
+    ///
+    /// See `strongly_connected_components::strongly_connected_components`
+    pub fn strongly_connected_components(&self) -> StronglyConnectedComponents<T::Id> {
+        strongly_connected_components::strongly_connected_components(self)
+    }
 
-        // Remove edges from this vertex
-        self.edges.remove(&vertex_id);
+    /// Return a graph whose vertices are the strongly connected
+    /// components of the graph, identified by their index
+    ///
+    /// See `strongly_connected_components::condensation`
+    pub fn condensation(&self) -> OrientedGraph<(usize, Vec<T::Id>)> {
+        strongly_connected_components::condensation(self)
+    }
 
-        // Remove edges to this vertex
-        for edges_set in self.edges.values_mut() {
-            edges_set.retain(|elem| *elem != vertex_id);
-        }
+    /// Return the articulation points, bridges and biconnected components
+    /// of the graph, ignoring the orientation of the edges
+    ///
+    /// See `biconnected_components::biconnectivity`
+    pub fn biconnectivity(&self) -> Biconnectivity<T::Id> {
+        biconnected_components::biconnectivity(self)
+    }
 
-        // Remove the vertex
-        self.vertices.remove(&vertex_id);
+    /// Return the vertices whose removal disconnects their component,
+    /// ignoring the orientation of the edges
+    ///
+    /// See `biconnected_components::articulation_points`
+    pub fn articulation_points(&self) -> Vec<T::Id> {
+        biconnected_components::articulation_points(self)
+    }
 
-        Ok(())
+    /// Return the edges whose removal disconnects their component,
+    /// ignoring the orientation of the edges
+    ///
+    /// See `biconnected_components::bridges`
+    pub fn bridges(&self) -> Vec<(T::Id, T::Id)> {
+        biconnected_components::bridges(self)
     }
 
-    /// Denote if a vertex exists
-    pub fn vertex_exists(&self, id: &String) -> bool {
-        self.vertices.contains_key(id)
+    /// Return the vertex ids of each biconnected component of the graph,
+    /// ignoring the orientation of the edges
+    ///
+    /// See `biconnected_components::biconnected_components`
+    pub fn biconnected_components(&self) -> Vec<Vec<T::Id>> {
+        biconnected_components::biconnected_components(self)
     }
+}
 
-    /// Check if a vertex exists and return Ok, or Err it does not exists
-    pub fn vertex_exists_or_err(&self, id: &String) -> Result<(), String> {
-        if self.vertex_exists(id) {
-            Ok(())
-        } else {
-            Err("Vertex ".to_owned() + id + " does not exist")
-        }
+impl<T: Identifiable> OrientedGraph<T>
+where
+    T::Id: Display,
+{
+    /// Return the DOT (Graphviz) representation of the graph
+    ///
+    /// See `dot::to_dot`
+    pub fn to_dot(&self) -> String {
+        dot::to_dot(self, |_| None, &[])
     }
 
-    /// Traverse the graph depth fist starting from the vertex specified in parameter
+    /// Return the DOT (Graphviz) representation of the graph,
+    /// with the vertices and edges of a path drawn in red
     ///
-    /// Note: this version is implemented using the call stack (recursive)
-    pub fn traverse_depth_first_recursive(
-        &self,
-        start_vertex_id: String,
-    ) -> Result<Vec<String>, String> {
-        let mut result = Vec::new();
-        let mut seen = HashSet::new();
+    /// See `dot::to_dot`
+    pub fn to_dot_highlighting(&self, path: &[T::Id]) -> String {
+        dot::to_dot(self, |_| None, path)
+    }
+}
 
-        self.internal_traverse_depth_first_recursive(start_vertex_id, &mut result, &mut seen)?;
+impl<T: Identifiable> OrientedGraph<T> {
+    /// Build a graph from its DOT (Graphviz) representation, the vertices
+    /// being built from their DOT ids by `vertex`, or Err if it is invalid
+    ///
+    /// See `dot::parse_oriented_graph`
+    pub fn from_dot<E: Display>(
+        input: &str,
+        vertex: impl Fn(&str) -> Result<T, E>,
+    ) -> Result<Self, DotError<T::Id>> {
+        dot::parse_oriented_graph(input, vertex)
+    }
+}
 
-        Ok(result)
+impl<T: Identifiable> Default for OrientedGraph<T> {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /// Traverse the graph depth fist starting from the vertex specified in parameter
-    ///
-    /// Note: this version is implemented using an explicit stack and a loop (iterative)
-    fn internal_traverse_depth_first_recursive(
-        &self,
-        vertex_id: String,
-        result: &mut Vec<String>,
-        seen: &mut HashSet<String>,
-    ) -> Result<(), String> {
-        // Check for invalid vertex
-        self.vertex_exists_or_err(&vertex_id)?;
-
-        // Add vertex to results
-        result.push(vertex_id.clone());
-
-        // Note vertex as visited
-        seen.insert(vertex_id.clone());
-
-        // Recursively traverse neighbors
-        for neighbor_id in self.edges.get(&vertex_id).unwrap() {
-            // if neighbor has not been visited yet
-            if !seen.contains(neighbor_id) {
-                self.internal_traverse_depth_first_recursive(neighbor_id.clone(), result, seen)?;
-            }
-        }
+impl<T: Identifiable> Graph for OrientedGraph<T> {
+    type Vertex = T;
+    type Weight = ();
 
-        Ok(())
+    fn vertex(&self, id: &T::Id) -> Option<&T> {
+        self.vertices.get(id)
     }
 
-    /// Traverse the graph breadth fist starting from the vertex specified in parameter
-    ///
-    /// Note: this version is implemented using an explicit queue and a loop (iterative)
-    pub fn traverse_depth_first_iterative(
-        &self,
-        start_vertex_id: String,
-    ) -> Result<Vec<String>, String> {
-        let mut result = Vec::new();
-        let mut seen = HashSet::new();
-        let mut stack = Vec::new();
-
-        // Init the stack with the starting node
-        stack.push(start_vertex_id);
-
-        while let Some(vertex_id) = stack.pop() {
-            // Check that vertex is valid
-            self.vertex_exists_or_err(&vertex_id)?;
-
-            // Add vertex to result
-            result.push(vertex_id.clone());
-
-            // Mark vertex as seen
-            seen.insert(vertex_id.clone());
-
-            // Push neighbors vertices to the stack
-            for neighbor_id in self.edges.get(&vertex_id).unwrap() {
-                if !seen.contains(neighbor_id) {
-                    stack.push(neighbor_id.clone());
-                }
-            }
-        }
+    fn vertices(&self) -> impl Iterator<Item = &T> {
+        self.vertices.values()
+    }
 
-        Ok(result)
+    fn vertex_ids(&self) -> impl Iterator<Item = &T::Id> {
+        self.vertices.keys()
     }
 
-    pub fn traverse_breadth_first(&self, start_vertex_id: String) -> Result<Vec<String>, String> {
-        let mut result = Vec::new();
-        let mut seen = HashSet::new();
-        let mut queue = VecDeque::new();
+    fn neighbors<'a>(
+        &'a self,
+        id: &T::Id,
+    ) -> Result<impl Iterator<Item = (&'a T::Id, &'a ())> + use<'a, T>, GraphError<T::Id>> {
+        self.edges
+            .get(id)
+            .map(|ids| ids.iter().map(|neighbor_id| (neighbor_id, &())))
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))
+    }
 
-        // Init the queue with the starting node
-        queue.push_back(start_vertex_id.clone());
+    fn edge(&self, from: &T::Id, to: &T::Id) -> Option<&()> {
+        self.edges
+            .get(from)
+            .filter(|ids| ids.contains(to))
+            .map(|_| &())
+    }
 
-        while let Some(vertex_id) = queue.pop_front() {
-            // Check that vertex is valid
-            self.vertex_exists_or_err(&vertex_id)?;
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
 
-            // Mark vertex as seen
-            seen.insert(vertex_id.clone());
+    fn edge_count(&self) -> usize {
+        self.edges.values().map(|ids| ids.len()).sum()
+    }
+}
 
-            // Add vertex to result
-            result.push(vertex_id.clone());
+impl<T: Identifiable> GraphMut for OrientedGraph<T> {
+    /// Add a vertex into the graph, and Err if it already exists
+    fn add_vertex(&mut self, vertex: T) -> Result<(), GraphError<T::Id>> {
+        let vertex_id = vertex.get_id().clone();
 
-            // Push vertex unseen neighbors to the queue
-            for neighbor_id in self.edges.get(&vertex_id).unwrap() {
-                if !seen.contains(neighbor_id) {
-                    queue.push_back(neighbor_id.clone());
-                }
-            }
+        // Err if vertex already exists
+        // Or insert the vertex in the graph
+        if self.vertex_exists(&vertex_id) {
+            Err(GraphError::VertexAlreadyExists(vertex_id))
+        } else {
+            self.vertices.insert(vertex_id.clone(), vertex);
+            self.edges.insert(vertex_id, HashSet::new());
+            Ok(())
         }
+    }
 
-        Ok(result)
+    fn add_edge(&mut self, from: &T::Id, to: &T::Id, _weight: ()) -> Result<(), GraphError<T::Id>> {
+        OrientedGraph::add_edge(self, from, to)
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// Try to remove an edge and return an Err if it was not present
+    fn remove_edge(
+        &mut self,
+        vertex1_id: &T::Id,
+        vertex2_id: &T::Id,
+    ) -> Result<(), GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(vertex1_id)?;
+        self.vertex_exists_or_err(vertex2_id)?;
 
-    // Basic struct for testing purpose
-    #[derive(PartialEq, Clone, Debug)]
-    struct City {
-        pub name: String,
+        // Try to remove the value
+        let result = self
+            .edges
+            .get_mut(vertex1_id)
+            .map(|ids| ids.remove(vertex2_id));
+
+        // If the edge was present and removed, return Ok, else Err
+        if result == Some(true) {
+            Ok(())
+        } else {
+            Err(GraphError::EdgeNotFound {
+                from: vertex1_id.clone(),
+                to: vertex2_id.clone(),
+            })
+        }
     }
 
-    impl Identifiable for City {
-        fn get_id(&self) -> String {
-            self.name.clone()
+    /// Try to remove a vertex and return an Err if it was not present
+    fn remove_vertex(&mut self, vertex_id: &T::Id) -> Result<(), GraphError<T::Id>> {
+        // Check that vertex exist, or err
+        self.vertex_exists_or_err(vertex_id)?;
+
+        // Remove edges from this vertex
+        self.edges.remove(vertex_id);
+
+        // Remove edges to this vertex
+        for edges_set in self.edges.values_mut() {
+            edges_set.remove(vertex_id);
         }
+
+        // Remove the vertex
+        self.vertices.remove(vertex_id);
+
+        Ok(())
     }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::{city, City};
+    use super::*;
 
     #[test]
     fn test_new() {
@@ -265,12 +307,10 @@ mod test {
     }
 
     #[test]
-    fn test_add_vertex() -> Result<(), String> {
+    fn test_add_vertex() -> Result<(), GraphError<String>> {
         let mut graph: OrientedGraph<City> = OrientedGraph::new();
 
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
+        let tokio = city("Tokio");
 
         // Adding a city should work
         let mut result = graph.add_vertex(tokio.clone());
@@ -279,27 +319,26 @@ mod test {
 
         // Adding the same city should Err
         result = graph.add_vertex(tokio);
-        assert_eq!(result, Err("Vertex Tokio already exists".to_string()));
+        assert_eq!(
+            result,
+            Err(GraphError::VertexAlreadyExists("Tokio".to_string()))
+        );
 
         Ok(())
     }
 
     #[test]
-    fn test_add_edge() -> Result<(), String> {
+    fn test_add_edge() -> Result<(), GraphError<String>> {
         let mut graph: OrientedGraph<City> = OrientedGraph::new();
 
         // Add cities
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
-        let vancouver = City {
-            name: "Vancouver".to_string(),
-        };
+        let tokio = city("Tokio");
+        let vancouver = city("Vancouver");
         let _ = graph.add_vertex(tokio.clone())?;
         let _ = graph.add_vertex(vancouver.clone())?;
 
         // Adding the edge should work
-        let mut result = graph.add_edge(tokio.name.clone(), vancouver.name.clone());
+        let mut result = graph.add_edge(&tokio.name, &vancouver.name);
         assert_eq!(result, Ok(()));
         assert_eq!(
             graph
@@ -311,82 +350,80 @@ mod test {
         );
 
         // Adding an edge with an absent city should Err
-        result = graph.add_edge(tokio.name.clone(), "Montreal".to_string());
-        assert_eq!(result, Err("Vertex Montreal does not exist".to_string()));
+        result = graph.add_edge(&tokio.name, &"Montreal".to_string());
+        assert_eq!(
+            result,
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
 
         // Adding an existing edge should Err
-        result = graph.add_edge(tokio.name.clone(), vancouver.name.clone());
+        result = graph.add_edge(&tokio.name, &vancouver.name);
         assert_eq!(
             result,
-            Err("Edge between from vertex Tokio to vertex Vancouver already exists".to_string())
+            Err(GraphError::EdgeAlreadyExists {
+                from: "Tokio".to_string(),
+                to: "Vancouver".to_string()
+            })
         );
 
         Ok(())
     }
 
     #[test]
-    fn test_remove_edge() -> Result<(), String> {
+    fn test_remove_edge() -> Result<(), GraphError<String>> {
         let mut graph: OrientedGraph<City> = OrientedGraph::new();
 
         // Add cities
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
-        let vancouver = City {
-            name: "Vancouver".to_string(),
-        };
+        let tokio = city("Tokio");
+        let vancouver = city("Vancouver");
         let _ = graph.add_vertex(tokio.clone())?;
         let _ = graph.add_vertex(vancouver.clone())?;
-        let _ = graph.add_edge(tokio.name.clone(), vancouver.name.clone())?;
+        let _ = graph.add_edge(&tokio.name, &vancouver.name)?;
 
         // Should return error if vertex does not exists
         assert_eq!(
-            graph.remove_edge(tokio.name.clone(), "Montreal".to_string()),
-            Err("Vertex Montreal does not exist".to_string())
+            graph.remove_edge(&tokio.name, &"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
         );
 
         // Should return error if edge does not exists
         assert_eq!(
-            graph.remove_edge(vancouver.name.clone(), tokio.name.clone()),
-            Err("Edge from vertex Vancouver to vertex Tokio does not exist".to_string())
+            graph.remove_edge(&vancouver.name, &tokio.name),
+            Err(GraphError::EdgeNotFound {
+                from: "Vancouver".to_string(),
+                to: "Tokio".to_string()
+            })
         );
 
         // Should remove edge
-        assert_eq!(
-            graph.remove_edge(tokio.name.clone(), vancouver.name.clone()),
-            Ok(())
-        );
+        assert_eq!(graph.remove_edge(&tokio.name, &vancouver.name), Ok(()));
         assert!(graph.edges.get(&tokio.name).unwrap().is_empty());
 
         Ok(())
     }
 
     #[test]
-    fn test_remove_vertex() -> Result<(), String> {
+    fn test_remove_vertex() -> Result<(), GraphError<String>> {
         let mut graph: OrientedGraph<City> = OrientedGraph::new();
 
         // Add cities
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
-        let vancouver = City {
-            name: "Vancouver".to_string(),
-        };
+        let tokio = city("Tokio");
+        let vancouver = city("Vancouver");
         let _ = graph.add_vertex(tokio.clone())?;
         let _ = graph.add_vertex(vancouver.clone())?;
 
         // Add edges
-        let _ = graph.add_edge(tokio.name.clone(), vancouver.name.clone())?;
-        let _ = graph.add_edge(vancouver.name.clone(), tokio.name.clone())?;
+        let _ = graph.add_edge(&tokio.name, &vancouver.name)?;
+        let _ = graph.add_edge(&vancouver.name, &tokio.name)?;
 
         // Should return error if vertex does not exists
         assert_eq!(
-            graph.remove_vertex("Montreal".to_string()),
-            Err("Vertex Montreal does not exist".to_string())
+            graph.remove_vertex(&"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
         );
 
         // Should remove vertex and associated edges
-        assert_eq!(graph.remove_vertex(tokio.name.clone()), Ok(()));
+        assert_eq!(graph.remove_vertex(&tokio.name), Ok(()));
         assert_eq!(graph.vertices.get(&tokio.name), None);
         assert_eq!(graph.edges.get(&tokio.name), None);
         assert!(graph.edges.get(&vancouver.name).unwrap().is_empty());
@@ -396,36 +433,30 @@ mod test {
 
     // Note: this test is very basic and should be improved
     #[test]
-    fn test_traverse_depth_first_recursive() -> Result<(), String> {
+    fn test_traverse_depth_first_recursive() -> Result<(), GraphError<String>> {
         let mut graph: OrientedGraph<City> = OrientedGraph::new();
 
         // Add cities
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
-        let paris = City {
-            name: "Paris".to_string(),
-        };
-        let vancouver = City {
-            name: "Vancouver".to_string(),
-        };
+        let tokio = city("Tokio");
+        let paris = city("Paris");
+        let vancouver = city("Vancouver");
         let _ = graph.add_vertex(tokio.clone())?;
         let _ = graph.add_vertex(vancouver.clone())?;
         let _ = graph.add_vertex(paris.clone())?;
 
         // Add edges
-        let _ = graph.add_edge(tokio.name.clone(), vancouver.name.clone())?;
-        let _ = graph.add_edge(tokio.name.clone(), paris.name.clone())?;
-        let _ = graph.add_edge(vancouver.name.clone(), tokio.name.clone())?;
+        let _ = graph.add_edge(&tokio.name, &vancouver.name)?;
+        let _ = graph.add_edge(&tokio.name, &paris.name)?;
+        let _ = graph.add_edge(&vancouver.name, &tokio.name)?;
 
         // Should return error if node is node in the graph
         assert_eq!(
-            graph.traverse_depth_first_recursive("Montreal".to_string()),
-            Err("Vertex Montreal does not exist".to_string())
+            graph.traverse_depth_first_recursive(&"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
         );
 
         // Should return the list of explored nodes
-        let result = graph.traverse_depth_first_recursive(tokio.name.clone());
+        let result = graph.traverse_depth_first_recursive(&tokio.name);
 
         print!("Result {:#?}", result);
 
@@ -438,36 +469,30 @@ mod test {
 
     // Note: this test is very basic and should be improved
     #[test]
-    fn test_traverse_depth_first_iterative() -> Result<(), String> {
+    fn test_traverse_depth_first_iterative() -> Result<(), GraphError<String>> {
         let mut graph: OrientedGraph<City> = OrientedGraph::new();
 
         // Add cities
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
-        let paris = City {
-            name: "Paris".to_string(),
-        };
-        let vancouver = City {
-            name: "Vancouver".to_string(),
-        };
+        let tokio = city("Tokio");
+        let paris = city("Paris");
+        let vancouver = city("Vancouver");
         let _ = graph.add_vertex(tokio.clone())?;
         let _ = graph.add_vertex(vancouver.clone())?;
         let _ = graph.add_vertex(paris.clone())?;
 
         // Add edges
-        let _ = graph.add_edge(tokio.name.clone(), vancouver.name.clone())?;
-        let _ = graph.add_edge(tokio.name.clone(), paris.name.clone())?;
-        let _ = graph.add_edge(vancouver.name.clone(), tokio.name.clone())?;
+        let _ = graph.add_edge(&tokio.name, &vancouver.name)?;
+        let _ = graph.add_edge(&tokio.name, &paris.name)?;
+        let _ = graph.add_edge(&vancouver.name, &tokio.name)?;
 
         // Should return error if node is node in the graph
         assert_eq!(
-            graph.traverse_depth_first_iterative("Montreal".to_string()),
-            Err("Vertex Montreal does not exist".to_string())
+            graph.traverse_depth_first_iterative(&"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
         );
 
         // Should return the list of explored nodes
-        let result = graph.traverse_depth_first_iterative(tokio.name.clone());
+        let result = graph.traverse_depth_first_iterative(&tokio.name);
 
         print!("Result {:#?}", result);
 
@@ -480,36 +505,30 @@ mod test {
 
     // Note: this test is very basic and should be improved
     #[test]
-    fn test_traverse_breadth_first() -> Result<(), String> {
+    fn test_traverse_breadth_first() -> Result<(), GraphError<String>> {
         let mut graph: OrientedGraph<City> = OrientedGraph::new();
 
         // Add cities
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
-        let paris = City {
-            name: "Paris".to_string(),
-        };
-        let vancouver = City {
-            name: "Vancouver".to_string(),
-        };
+        let tokio = city("Tokio");
+        let paris = city("Paris");
+        let vancouver = city("Vancouver");
         let _ = graph.add_vertex(tokio.clone())?;
         let _ = graph.add_vertex(vancouver.clone())?;
         let _ = graph.add_vertex(paris.clone())?;
 
         // Add edges
-        let _ = graph.add_edge(tokio.name.clone(), vancouver.name.clone())?;
-        let _ = graph.add_edge(tokio.name.clone(), paris.name.clone())?;
-        let _ = graph.add_edge(vancouver.name.clone(), tokio.name.clone())?;
+        let _ = graph.add_edge(&tokio.name, &vancouver.name)?;
+        let _ = graph.add_edge(&tokio.name, &paris.name)?;
+        let _ = graph.add_edge(&vancouver.name, &tokio.name)?;
 
         // Should return error if node is node in the graph
         assert_eq!(
-            graph.traverse_breadth_first("Montreal".to_string()),
-            Err("Vertex Montreal does not exist".to_string())
+            graph.traverse_breadth_first(&"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
         );
 
         // Should return the list of explored nodes
-        let result = graph.traverse_breadth_first(tokio.name.clone());
+        let result = graph.traverse_breadth_first(&tokio.name);
 
         print!("Result {:#?}", result);
 
@@ -1,27 +1,39 @@
-use std::u32::MAX;
-use std::{
-    cmp,
-    collections::{BinaryHeap, HashMap, HashSet},
-};
-
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::Sub;
+use std::str::FromStr;
+
+use super::a_star;
+use super::all_pairs_shortest_paths::{self, DistanceMatrixResult};
+use super::bellman_ford;
+use super::biconnected_components::{self, Biconnectivity};
+use super::dot::{self, DotError};
+use super::error::GraphError;
+use super::graph_trait::{Graph, GraphMut};
 use super::identifiable::Identifiable;
+use super::max_flow::{self, MaxFlowResult};
+use super::minimum_spanning_tree::{self, SpanningForestResult};
+use super::shortest_path::{self, PathResult, ShortestPathsResult};
+use super::weight::Weight;
 
 /// This is the same Graph than `OrientedGraph` except that
 /// it is weighted
+///
+/// The weights are of type `W`, `u32` by default
 #[derive(Debug)]
-pub struct OrientedWeightedGraph<T: Identifiable> {
+pub struct OrientedWeightedGraph<T: Identifiable, W = u32> {
     /// List of the graph vertices
-    pub vertices: HashMap<String, T>,
+    pub vertices: HashMap<T::Id, T>,
 
     /// List of the graph edges
     ///
     /// Note: The way it is structured allow to express oriented weighted edges
     /// - A -> B by storing the entry { A: { B: weight } }
     /// - B -> A by storing the entry { B: { A: weight } }
-    pub edges: HashMap<String, HashMap<String, u32>>,
+    pub edges: HashMap<T::Id, HashMap<T::Id, W>>,
 }
 
-impl<T: Identifiable> OrientedWeightedGraph<T> {
+impl<T: Identifiable, W> OrientedWeightedGraph<T, W> {
     /// Instantiate a new graph
     pub fn new() -> Self {
         Self {
@@ -30,14 +42,290 @@ impl<T: Identifiable> OrientedWeightedGraph<T> {
         }
     }
 
+    /// Return the weight of an edge, or Err if it does not exist
+    pub fn edge_weight(&self, from: &T::Id, to: &T::Id) -> Result<&W, GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(from)?;
+        self.vertex_exists_or_err(to)?;
+
+        self.edges[from]
+            .get(to)
+            .ok_or_else(|| GraphError::EdgeNotFound {
+                from: from.clone(),
+                to: to.clone(),
+            })
+    }
+
+    /// Replace the weight of an edge, and return the previous one,
+    /// or Err if the edge does not exist
+    pub fn set_weight(
+        &mut self,
+        from: &T::Id,
+        to: &T::Id,
+        weight: W,
+    ) -> Result<W, GraphError<T::Id>> {
+        let current_weight = self.edge_weight_mut(from, to)?;
+
+        Ok(std::mem::replace(current_weight, weight))
+    }
+
+    /// Update the weight of an edge in place, or Err if the edge does not exist
+    pub fn update_weight(
+        &mut self,
+        from: &T::Id,
+        to: &T::Id,
+        update: impl FnOnce(&mut W),
+    ) -> Result<(), GraphError<T::Id>> {
+        update(self.edge_weight_mut(from, to)?);
+
+        Ok(())
+    }
+
+    /// Add an edge into the graph, or replace its weight if it already exists,
+    /// and return the previous weight, or Err if it targets invalid vertices
+    pub fn upsert_edge(
+        &mut self,
+        from: &T::Id,
+        to: &T::Id,
+        weight: W,
+    ) -> Result<Option<W>, GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(from)?;
+        self.vertex_exists_or_err(to)?;
+
+        Ok(self.edges.get_mut(from).unwrap().insert(to.clone(), weight))
+    }
+
+    /// Return a mutable reference to the weight of an edge, or Err if it does not exist
+    fn edge_weight_mut(&mut self, from: &T::Id, to: &T::Id) -> Result<&mut W, GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(from)?;
+        self.vertex_exists_or_err(to)?;
+
+        self.edges
+            .get_mut(from)
+            .unwrap()
+            .get_mut(to)
+            .ok_or_else(|| GraphError::EdgeNotFound {
+                from: from.clone(),
+                to: to.clone(),
+            })
+    }
+
+    /// Return the articulation points, bridges and biconnected components
+    /// of the graph, ignoring the orientation of the edges
+    ///
+    /// See `biconnected_components::biconnectivity`
+    pub fn biconnectivity(&self) -> Biconnectivity<T::Id> {
+        biconnected_components::biconnectivity(self)
+    }
+
+    /// Return the vertices whose removal disconnects their component,
+    /// ignoring the orientation of the edges
+    ///
+    /// See `biconnected_components::articulation_points`
+    pub fn articulation_points(&self) -> Vec<T::Id> {
+        biconnected_components::articulation_points(self)
+    }
+
+    /// Return the edges whose removal disconnects their component,
+    /// ignoring the orientation of the edges
+    ///
+    /// See `biconnected_components::bridges`
+    pub fn bridges(&self) -> Vec<(T::Id, T::Id)> {
+        biconnected_components::bridges(self)
+    }
+
+    /// Return the vertex ids of each biconnected component of the graph,
+    /// ignoring the orientation of the edges
+    ///
+    /// See `biconnected_components::biconnected_components`
+    pub fn biconnected_components(&self) -> Vec<Vec<T::Id>> {
+        biconnected_components::biconnected_components(self)
+    }
+}
+
+impl<T: Identifiable, W: Weight> OrientedWeightedGraph<T, W> {
+    /// Return the shortest path between two vertices of the graph,
+    /// or None if the target can not be reached from the source
+    ///
+    /// See `shortest_path::shortest_path`
+    pub fn shortest_path(&self, source_id: &T::Id, target_id: &T::Id) -> PathResult<Self> {
+        shortest_path::shortest_path(self, source_id, target_id)
+    }
+
+    /// Return the shortest paths from a source vertex to every vertex it can reach
+    ///
+    /// See `shortest_path::shortest_paths_from`
+    pub fn shortest_paths_from(&self, source_id: &T::Id) -> ShortestPathsResult<Self> {
+        shortest_path::shortest_paths_from(self, source_id)
+    }
+
+    /// Return the shortest path between two vertices of the graph, using an heuristic
+    /// estimating the distance between a vertex and the target to explore less vertices
+    ///
+    /// See `a_star::a_star`
+    pub fn a_star(
+        &self,
+        source_id: &T::Id,
+        target_id: &T::Id,
+        heuristic: impl Fn(&T) -> W,
+    ) -> PathResult<Self> {
+        a_star::a_star(self, source_id, target_id, heuristic)
+    }
+
+    /// Return the shortest paths from a source vertex to every vertex it can reach,
+    /// supporting negative weights, or Err if a negative cycle can be reached
+    ///
+    /// See `bellman_ford::bellman_ford`
+    pub fn bellman_ford(&self, source_id: &T::Id) -> ShortestPathsResult<Self> {
+        bellman_ford::bellman_ford(self, source_id)
+    }
+
+    /// Return the shortest paths between every pair of vertices, best suited
+    /// for dense graphs, or Err if there is a negative cycle
+    ///
+    /// See `all_pairs_shortest_paths::floyd_warshall`
+    pub fn floyd_warshall(&self) -> DistanceMatrixResult<Self> {
+        all_pairs_shortest_paths::floyd_warshall(self)
+    }
+
+    /// Return the shortest paths between every pair of vertices, best suited
+    /// for sparse graphs, or Err if there is a negative cycle
+    ///
+    /// See `all_pairs_shortest_paths::johnson`
+    pub fn johnson(&self) -> DistanceMatrixResult<Self> {
+        all_pairs_shortest_paths::johnson(self)
+    }
+
+    /// Return the maximum flow from a source vertex to a sink vertex,
+    /// the weights being the capacities of the edges
+    ///
+    /// See `max_flow::edmonds_karp`
+    pub fn edmonds_karp(&self, source_id: &T::Id, sink_id: &T::Id) -> MaxFlowResult<Self>
+    where
+        W: Sub<Output = W>,
+    {
+        max_flow::edmonds_karp(self, source_id, sink_id)
+    }
+
+    /// Return the maximum flow from a source vertex to a sink vertex,
+    /// the weights being the capacities of the edges
+    ///
+    /// See `max_flow::dinic`
+    pub fn dinic(&self, source_id: &T::Id, sink_id: &T::Id) -> MaxFlowResult<Self>
+    where
+        W: Sub<Output = W>,
+    {
+        max_flow::dinic(self, source_id, sink_id)
+    }
+
+    /// Return the edges linking all the vertices with the smallest total
+    /// weight (a forest if the graph is not connected), ignoring their orientation
+    ///
+    /// See `minimum_spanning_tree::kruskal`
+    pub fn kruskal(&self) -> SpanningForestResult<Self> {
+        minimum_spanning_tree::kruskal(self)
+    }
+
+    /// Return the edges linking all the vertices with the smallest total
+    /// weight (a forest if the graph is not connected), ignoring their orientation
+    ///
+    /// See `minimum_spanning_tree::prim`
+    pub fn prim(&self) -> SpanningForestResult<Self> {
+        minimum_spanning_tree::prim(self)
+    }
+}
+
+impl<T: Identifiable, W: Display> OrientedWeightedGraph<T, W>
+where
+    T::Id: Display,
+{
+    /// Return the DOT (Graphviz) representation of the graph,
+    /// with the weights as edge labels
+    ///
+    /// See `dot::to_dot`
+    pub fn to_dot(&self) -> String {
+        dot::to_dot(self, |weight| Some(weight.to_string()), &[])
+    }
+
+    /// Return the DOT (Graphviz) representation of the graph, with the weights
+    /// as edge labels, and the vertices and edges of a path drawn in red
+    /// (for example the vertices of a `shortest_path` result)
+    ///
+    /// See `dot::to_dot`
+    pub fn to_dot_highlighting(&self, path: &[T::Id]) -> String {
+        dot::to_dot(self, |weight| Some(weight.to_string()), path)
+    }
+}
+
+impl<T: Identifiable, W: FromStr> OrientedWeightedGraph<T, W> {
+    /// Build a graph from its DOT (Graphviz) representation, the vertices
+    /// being built from their DOT ids by `vertex`, or Err if it is invalid
+    ///
+    /// See `dot::parse_oriented_weighted_graph`
+    pub fn from_dot<E: Display>(
+        input: &str,
+        vertex: impl Fn(&str) -> Result<T, E>,
+    ) -> Result<Self, DotError<T::Id>> {
+        dot::parse_oriented_weighted_graph(input, vertex)
+    }
+}
+
+impl<T: Identifiable, W> Default for OrientedWeightedGraph<T, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Identifiable, W> Graph for OrientedWeightedGraph<T, W> {
+    type Vertex = T;
+    type Weight = W;
+
+    fn vertex(&self, id: &T::Id) -> Option<&T> {
+        self.vertices.get(id)
+    }
+
+    fn vertices(&self) -> impl Iterator<Item = &T> {
+        self.vertices.values()
+    }
+
+    fn vertex_ids(&self) -> impl Iterator<Item = &T::Id> {
+        self.vertices.keys()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        id: &T::Id,
+    ) -> Result<impl Iterator<Item = (&'a T::Id, &'a W)> + use<'a, T, W>, GraphError<T::Id>> {
+        self.edges
+            .get(id)
+            .map(|weights| weights.iter())
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))
+    }
+
+    fn edge(&self, from: &T::Id, to: &T::Id) -> Option<&W> {
+        self.edges.get(from).and_then(|weights| weights.get(to))
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn edge_count(&self) -> usize {
+        self.edges.values().map(|weights| weights.len()).sum()
+    }
+}
+
+impl<T: Identifiable, W> GraphMut for OrientedWeightedGraph<T, W> {
     /// Add a vertex into the graph, and Err if it already exists
-    pub fn add_vertex(&mut self, vertex: T) -> Result<(), String> {
-        let vertex_id = vertex.get_id();
+    fn add_vertex(&mut self, vertex: T) -> Result<(), GraphError<T::Id>> {
+        let vertex_id = vertex.get_id().clone();
 
         // Err if vertex already exists
         // Or insert the vertex in the graph
         if self.vertex_exists(&vertex_id) {
-            Err("Vertex ".to_owned() + &vertex_id + " already exists")
+            Err(GraphError::VertexAlreadyExists(vertex_id))
         } else {
             self.vertices.insert(vertex_id.clone(), vertex);
             self.edges.insert(vertex_id, HashMap::new());
@@ -46,199 +334,86 @@ impl<T: Identifiable> OrientedWeightedGraph<T> {
     }
 
     /// Add an edge into the graph, and Err if it already exists, of if it targets invalid vertices
-    pub fn add_edge(
+    fn add_edge(
         &mut self,
-        vertex1_id: String,
-        vertex2_id: String,
-        weight: u32,
-    ) -> Result<(), String> {
+        vertex1_id: &T::Id,
+        vertex2_id: &T::Id,
+        weight: W,
+    ) -> Result<(), GraphError<T::Id>> {
         // Check that vertices exist, or err
-        self.vertex_exists_or_err(&vertex1_id)?;
-        self.vertex_exists_or_err(&vertex2_id)?;
+        self.vertex_exists_or_err(vertex1_id)?;
+        self.vertex_exists_or_err(vertex2_id)?;
 
         // Insert the new edge
-        let edge_exists = self
-            .edges
-            .get(&vertex1_id)
-            .unwrap()
-            .contains_key(&vertex2_id);
+        let edge_exists = self.edges.get(vertex1_id).unwrap().contains_key(vertex2_id);
 
         match edge_exists {
             false => {
                 self.edges
-                    .get_mut(&vertex1_id)
+                    .get_mut(vertex1_id)
                     .unwrap()
-                    .insert(vertex2_id, weight);
+                    .insert(vertex2_id.clone(), weight);
                 Ok(())
             }
-            true => Err("Edge between from vertex ".to_owned()
-                + &vertex1_id
-                + " to vertex "
-                + &vertex2_id
-                + " already exists"),
+            true => Err(GraphError::EdgeAlreadyExists {
+                from: vertex1_id.clone(),
+                to: vertex2_id.clone(),
+            }),
         }
     }
 
-    /// Denote if a vertex exists
-    pub fn vertex_exists(&self, id: &String) -> bool {
-        self.vertices.contains_key(id)
-    }
+    /// Try to remove an edge and return an Err if it was not present
+    fn remove_edge(
+        &mut self,
+        vertex1_id: &T::Id,
+        vertex2_id: &T::Id,
+    ) -> Result<(), GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(vertex1_id)?;
+        self.vertex_exists_or_err(vertex2_id)?;
 
-    /// Check if a vertex exists and return Ok, or Err it does not exists
-    pub fn vertex_exists_or_err(&self, id: &String) -> Result<(), String> {
-        if self.vertex_exists(id) {
-            Ok(())
-        } else {
-            Err("Vertex ".to_owned() + id + " does not exist")
+        // Try to remove the value
+        let result = self
+            .edges
+            .get_mut(vertex1_id)
+            .and_then(|weights| weights.remove(vertex2_id));
+
+        // If the edge was present and removed, return Ok, else Err
+        match result {
+            Some(_) => Ok(()),
+            None => Err(GraphError::EdgeNotFound {
+                from: vertex1_id.clone(),
+                to: vertex2_id.clone(),
+            }),
         }
     }
 
-    /// Return the shortest path between two vertices of the graph
-    ///
-    /// This function uses [Dijkstra's shortest path algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm)
-    pub fn shortest_path(
-        &self,
-        source_id: String,
-        target_id: String,
-    ) -> Result<Vec<String>, String> {
-        // Check that source and vertices exist, or err
-        self.vertex_exists_or_err(&source_id)?;
-        self.vertex_exists_or_err(&target_id)?;
-
-        // Keep track of the vertices visited in the main loop
-        let mut seen_vertices = HashSet::new();
-        // Keep track of the shorter distance between source and a given vertex
-        let mut shortest_distances_from_source = HashMap::new();
-        // Keep track of the shortest ways between source and a given vertex
-        // (This is stored as a linked list)
-        let mut path: HashMap<String, String> = HashMap::new();
-        // The priority queue is used to iterate step by step between vertices
-        // while always priorising the known vertex which is the closest from the
-        // source node
-        let mut priority_queue = BinaryHeap::new();
-        let mut result = Vec::new();
-
-        // Initialize the state with the knowledge we have
-        // - The distance between source and itself is 0
-        // - Source is the closes node we know
-        shortest_distances_from_source.insert(source_id.clone(), 0);
-        priority_queue.push(VertexByDistance {
-            id: source_id.clone(),
-            distance: 0,
-        });
-
-        // Loop through the vertices by "closest from the source" order
-        while let Some(VertexByDistance {
-            id: current_id,
-            distance: source_current_distance,
-        }) = priority_queue.pop()
-        {
-            // Mark the current_vertex as seen
-            seen_vertices.insert(current_id.clone());
-
-            // If we reach the target vertex, we stop
-            // Note: it could be possible to explore the whole
-            // graph to return a shortest-path tree
-            if current_id == target_id {
-                result.push(target_id.clone());
-                break;
-            }
+    /// Try to remove a vertex and return an Err if it was not present
+    fn remove_vertex(&mut self, vertex_id: &T::Id) -> Result<(), GraphError<T::Id>> {
+        // Check that vertex exist, or err
+        self.vertex_exists_or_err(vertex_id)?;
 
-            // Else, for each neighbor, check if the current path is shorter
-            // than a path explored previously, and update the state
-            for (neighbor_id, current_neighbor_distance) in self.edges.get(&current_id).unwrap() {
-                // Ignore neighbor vertices that have already been visited
-                if seen_vertices.contains(neighbor_id) {
-                    continue;
-                }
-
-                // distance from source->neighbor = distance from source->current + current->neighbor
-                let source_neighbor_distance = current_neighbor_distance + source_current_distance;
-
-                if source_neighbor_distance
-                    < *shortest_distances_from_source
-                        .get(neighbor_id)
-                        .unwrap_or(&MAX)
-                {
-                    // Update shortest distance from source, as a shorter path was found
-                    shortest_distances_from_source
-                        .insert(neighbor_id.clone(), source_neighbor_distance);
-
-                    // Update path with the shortest path that was found
-                    path.insert(neighbor_id.clone(), current_id.clone());
-
-                    // Insert the newly visited vertex in the priority queue
-                    // The next closer vertex from the source will be visited first
-                    priority_queue.push(VertexByDistance {
-                        id: neighbor_id.clone(),
-                        distance: source_neighbor_distance,
-                    });
-                }
-            }
-        }
+        // Remove edges from this vertex
+        self.edges.remove(vertex_id);
 
-        // The loop ends when the targe is reached or all
-        // paths were explored and none was find
-        // Next, build the path to target vertex by linearize
-        // the `path` hashmap
-        let mut curr_id = target_id.clone();
-        while let Some(next_id) = path.get(&curr_id) {
-            result.push(next_id.clone());
-            curr_id = next_id.clone();
+        // Remove edges to this vertex
+        for weights in self.edges.values_mut() {
+            weights.remove(vertex_id);
         }
 
-        // Reverse the result, as it first come backward
-        result.reverse();
+        // Remove the vertex
+        self.vertices.remove(vertex_id);
 
-        return Ok(result);
-    }
-}
-
-/// Type wrapper that implements Ord and Eq
-///
-/// It is used to be able to compare vertices by distance and
-/// sort them with a BinaryHeap priority queue
-struct VertexByDistance {
-    id: String,
-    distance: u32,
-}
-
-impl PartialEq for VertexByDistance {
-    fn eq(&self, other: &Self) -> bool {
-        self.distance == other.distance
-    }
-}
-
-impl PartialOrd for VertexByDistance {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.distance.cmp(&other.distance).reverse())
-    }
-}
-
-impl Ord for VertexByDistance {
-    fn cmp(&self, other: &Self) -> cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+        Ok(())
     }
 }
 
-impl Eq for VertexByDistance {}
-
 #[cfg(test)]
 mod test {
+    use super::super::shortest_path::Path;
+    use super::super::test_utils::{city, City};
     use super::*;
 
-    // Basic struct for testing purpose
-    #[derive(PartialEq, Clone, Debug)]
-    struct City {
-        pub name: String,
-    }
-
-    impl Identifiable for City {
-        fn get_id(&self) -> String {
-            self.name.clone()
-        }
-    }
-
     #[test]
     fn test_new() {
         let graph: OrientedWeightedGraph<City> = OrientedWeightedGraph::new();
@@ -248,12 +423,10 @@ mod test {
     }
 
     #[test]
-    fn test_add_vertex() -> Result<(), String> {
+    fn test_add_vertex() -> Result<(), GraphError<String>> {
         let mut graph: OrientedWeightedGraph<City> = OrientedWeightedGraph::new();
 
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
+        let tokio = city("Tokio");
 
         // Adding a city should work
         let mut result = graph.add_vertex(tokio.clone());
@@ -262,27 +435,26 @@ mod test {
 
         // Adding the same city should Err
         result = graph.add_vertex(tokio);
-        assert_eq!(result, Err("Vertex Tokio already exists".to_string()));
+        assert_eq!(
+            result,
+            Err(GraphError::VertexAlreadyExists("Tokio".to_string()))
+        );
 
         Ok(())
     }
 
     #[test]
-    fn test_add_edge() -> Result<(), String> {
+    fn test_add_edge() -> Result<(), GraphError<String>> {
         let mut graph: OrientedWeightedGraph<City> = OrientedWeightedGraph::new();
 
         // Add cities
-        let tokio = City {
-            name: "Tokio".to_string(),
-        };
-        let vancouver = City {
-            name: "Vancouver".to_string(),
-        };
+        let tokio = city("Tokio");
+        let vancouver = city("Vancouver");
         let _ = graph.add_vertex(tokio.clone())?;
         let _ = graph.add_vertex(vancouver.clone())?;
 
         // Adding the edge should work
-        let mut result = graph.add_edge(tokio.name.clone(), vancouver.name.clone(), 10);
+        let mut result = graph.add_edge(&tokio.name, &vancouver.name, 10);
         assert_eq!(result, Ok(()));
         assert_eq!(
             graph.edges.get(&tokio.name).unwrap().get(&vancouver.name),
@@ -290,42 +462,188 @@ mod test {
         );
 
         // Adding an edge with an absent city should Err
-        result = graph.add_edge(tokio.name.clone(), "Montreal".to_string(), 10);
-        assert_eq!(result, Err("Vertex Montreal does not exist".to_string()));
+        result = graph.add_edge(&tokio.name, &"Montreal".to_string(), 10);
+        assert_eq!(
+            result,
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
 
         // Adding an existing edge should Err
-        result = graph.add_edge(tokio.name.clone(), vancouver.name.clone(), 10);
+        result = graph.add_edge(&tokio.name, &vancouver.name, 10);
         assert_eq!(
             result,
-            Err("Edge between from vertex Tokio to vertex Vancouver already exists".to_string())
+            Err(GraphError::EdgeAlreadyExists {
+                from: "Tokio".to_string(),
+                to: "Vancouver".to_string()
+            })
         );
 
         Ok(())
     }
 
     #[test]
-    fn test_shortest_path() -> Result<(), String> {
+    fn test_remove_edge() -> Result<(), GraphError<String>> {
         let mut graph: OrientedWeightedGraph<City> = OrientedWeightedGraph::new();
 
         // Add cities
-        let a = City {
-            name: "a".to_string(),
-        };
-        let b = City {
-            name: "b".to_string(),
-        };
-        let c = City {
-            name: "c".to_string(),
-        };
-        let d = City {
-            name: "d".to_string(),
-        };
-        let e = City {
-            name: "e".to_string(),
-        };
-        let f = City {
-            name: "f".to_string(),
+        let tokio = city("Tokio");
+        let vancouver = city("Vancouver");
+        let _ = graph.add_vertex(tokio.clone())?;
+        let _ = graph.add_vertex(vancouver.clone())?;
+        let _ = graph.add_edge(&tokio.name, &vancouver.name, 10)?;
+
+        // Should return error if vertex does not exists
+        assert_eq!(
+            graph.remove_edge(&tokio.name, &"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        // Should return error if edge does not exists
+        assert_eq!(
+            graph.remove_edge(&vancouver.name, &tokio.name),
+            Err(GraphError::EdgeNotFound {
+                from: "Vancouver".to_string(),
+                to: "Tokio".to_string()
+            })
+        );
+
+        // Should remove edge
+        assert_eq!(graph.remove_edge(&tokio.name, &vancouver.name), Ok(()));
+        assert!(graph.edges.get(&tokio.name).unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_vertex() -> Result<(), GraphError<String>> {
+        let mut graph: OrientedWeightedGraph<City> = OrientedWeightedGraph::new();
+
+        // Add cities
+        let tokio = city("Tokio");
+        let vancouver = city("Vancouver");
+        let _ = graph.add_vertex(tokio.clone())?;
+        let _ = graph.add_vertex(vancouver.clone())?;
+
+        // Add edges
+        let _ = graph.add_edge(&tokio.name, &vancouver.name, 10)?;
+        let _ = graph.add_edge(&vancouver.name, &tokio.name, 12)?;
+
+        // Should return error if vertex does not exists
+        assert_eq!(
+            graph.remove_vertex(&"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        // Should remove vertex and associated edges, incoming edges included
+        assert_eq!(graph.remove_vertex(&tokio.name), Ok(()));
+        assert_eq!(graph.vertices.get(&tokio.name), None);
+        assert_eq!(graph.edges.get(&tokio.name), None);
+        assert!(graph.edges.get(&vancouver.name).unwrap().is_empty());
+        assert_eq!(graph.edge_count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_weight() -> Result<(), GraphError<String>> {
+        let mut graph: OrientedWeightedGraph<City> = OrientedWeightedGraph::new();
+
+        // Add cities
+        let tokio = city("Tokio");
+        let vancouver = city("Vancouver");
+        let _ = graph.add_vertex(tokio.clone())?;
+        let _ = graph.add_vertex(vancouver.clone())?;
+        let _ = graph.add_edge(&tokio.name, &vancouver.name, 10)?;
+
+        assert_eq!(graph.edge_weight(&tokio.name, &vancouver.name), Ok(&10));
+
+        // Replacing the weight should return the previous one
+        assert_eq!(graph.set_weight(&tokio.name, &vancouver.name, 12), Ok(10));
+        assert_eq!(graph.edge_weight(&tokio.name, &vancouver.name), Ok(&12));
+
+        // Updating the weight in place
+        graph.update_weight(&tokio.name, &vancouver.name, |weight| *weight *= 2)?;
+        assert_eq!(graph.edge_weight(&tokio.name, &vancouver.name), Ok(&24));
+
+        // Reading or writing an absent edge should Err
+        let edge_not_found = GraphError::EdgeNotFound {
+            from: "Vancouver".to_string(),
+            to: "Tokio".to_string(),
         };
+        assert_eq!(
+            graph.edge_weight(&vancouver.name, &tokio.name),
+            Err(edge_not_found.clone())
+        );
+        assert_eq!(
+            graph.set_weight(&vancouver.name, &tokio.name, 1),
+            Err(edge_not_found.clone())
+        );
+        assert_eq!(
+            graph.update_weight(&vancouver.name, &tokio.name, |weight| *weight += 1),
+            Err(edge_not_found)
+        );
+
+        // Reading or writing with an absent city should Err
+        let montreal = "Montreal".to_string();
+        let vertex_not_found = GraphError::VertexNotFound("Montreal".to_string());
+        assert_eq!(
+            graph.edge_weight(&tokio.name, &montreal),
+            Err(vertex_not_found.clone())
+        );
+        assert_eq!(
+            graph.set_weight(&montreal, &tokio.name, 1),
+            Err(vertex_not_found)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_upsert_edge() -> Result<(), GraphError<String>> {
+        let mut graph: OrientedWeightedGraph<City> = OrientedWeightedGraph::new();
+
+        // Add cities
+        let tokio = city("Tokio");
+        let vancouver = city("Vancouver");
+        let _ = graph.add_vertex(tokio.clone())?;
+        let _ = graph.add_vertex(vancouver.clone())?;
+
+        // Inserting a new edge has no previous weight
+        assert_eq!(
+            graph.upsert_edge(&tokio.name, &vancouver.name, 10),
+            Ok(None)
+        );
+        assert_eq!(graph.edge_weight(&tokio.name, &vancouver.name), Ok(&10));
+
+        // Upserting an existing edge replaces its weight
+        assert_eq!(
+            graph.upsert_edge(&tokio.name, &vancouver.name, 8),
+            Ok(Some(10))
+        );
+        assert_eq!(graph.edge_weight(&tokio.name, &vancouver.name), Ok(&8));
+        assert_eq!(graph.edge_count(), 1);
+
+        // Upserting with an absent city should Err
+        assert_eq!(
+            graph.upsert_edge(&tokio.name, &"Montreal".to_string(), 10),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        Ok(())
+    }
+
+    /// Build the graph used to test the shortest paths:
+    /// a -4-> b, a -2-> c, b -3-> e, c -2-> d, c -4-> f, d -3-> e, d -1-> f, f -1-> e
+    fn build_cities_graph() -> Result<OrientedWeightedGraph<City>, GraphError<String>> {
+        let mut graph: OrientedWeightedGraph<City> = OrientedWeightedGraph::new();
+
+        // Add cities
+        let a = city("a");
+        let b = city("b");
+        let c = city("c");
+        let d = city("d");
+        let e = city("e");
+        let f = city("f");
 
         graph.add_vertex(a.clone())?;
         graph.add_vertex(b.clone())?;
@@ -334,25 +652,80 @@ mod test {
         graph.add_vertex(e.clone())?;
         graph.add_vertex(f.clone())?;
 
-        graph.add_edge("a".to_string(), "b".to_string(), 4)?;
-        graph.add_edge("a".to_string(), "c".to_string(), 2)?;
-        graph.add_edge("b".to_string(), "e".to_string(), 3)?;
-        graph.add_edge("c".to_string(), "d".to_string(), 2)?;
-        graph.add_edge("c".to_string(), "f".to_string(), 4)?;
-        graph.add_edge("d".to_string(), "e".to_string(), 3)?;
-        graph.add_edge("d".to_string(), "f".to_string(), 1)?;
-        graph.add_edge("f".to_string(), "e".to_string(), 1)?;
+        graph.add_edge(&"a".to_string(), &"b".to_string(), 4)?;
+        graph.add_edge(&"a".to_string(), &"c".to_string(), 2)?;
+        graph.add_edge(&"b".to_string(), &"e".to_string(), 3)?;
+        graph.add_edge(&"c".to_string(), &"d".to_string(), 2)?;
+        graph.add_edge(&"c".to_string(), &"f".to_string(), 4)?;
+        graph.add_edge(&"d".to_string(), &"e".to_string(), 3)?;
+        graph.add_edge(&"d".to_string(), &"f".to_string(), 1)?;
+        graph.add_edge(&"f".to_string(), &"e".to_string(), 1)?;
+
+        Ok(graph)
+    }
+
+    #[test]
+    fn test_shortest_path() -> Result<(), GraphError<String>> {
+        let graph = build_cities_graph()?;
 
         // Validate a simple path
         assert_eq!(
-            graph.shortest_path("a".to_string(), "b".to_string()),
-            Ok(vec!["a".to_string(), "b".to_string(),])
+            graph.shortest_path(&"a".to_string(), &"b".to_string()),
+            Ok(Some(Path {
+                vertices: vec!["a".to_string(), "b".to_string()],
+                total_weight: 4
+            }))
         );
 
         // Validate complex path
         assert_eq!(
-            graph.shortest_path("a".to_string(), "e".to_string()),
-            Ok(vec![
+            graph.shortest_path(&"a".to_string(), &"e".to_string()),
+            Ok(Some(Path {
+                vertices: vec![
+                    "a".to_string(),
+                    "c".to_string(),
+                    "d".to_string(),
+                    "f".to_string(),
+                    "e".to_string(),
+                ],
+                total_weight: 6
+            }))
+        );
+
+        // Validate no path
+        assert_eq!(
+            graph.shortest_path(&"b".to_string(), &"a".to_string()),
+            Ok(None)
+        );
+
+        // Validate unknown vertex
+        assert_eq!(
+            graph.shortest_path(&"a".to_string(), &"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_paths_from() -> Result<(), GraphError<String>> {
+        let graph = build_cities_graph()?;
+
+        let tree = graph.shortest_paths_from(&"a".to_string())?;
+
+        // Validate distances
+        assert_eq!(tree.distance_to(&"a".to_string()), Some(0));
+        assert_eq!(tree.distance_to(&"b".to_string()), Some(4));
+        assert_eq!(tree.distance_to(&"d".to_string()), Some(4));
+        assert_eq!(tree.distance_to(&"e".to_string()), Some(6));
+        assert_eq!(tree.distances.len(), 6);
+
+        // Validate predecessors and paths
+        assert_eq!(tree.predecessor(&"a".to_string()), None);
+        assert_eq!(tree.predecessor(&"e".to_string()), Some(&"f".to_string()));
+        assert_eq!(
+            tree.path_to(&"e".to_string()).map(|path| path.vertices),
+            Some(vec![
                 "a".to_string(),
                 "c".to_string(),
                 "d".to_string(),
@@ -360,11 +733,41 @@ mod test {
                 "e".to_string(),
             ])
         );
+        assert_eq!(
+            tree.path_to(&"a".to_string()),
+            Some(Path {
+                vertices: vec!["a".to_string()],
+                total_weight: 0
+            })
+        );
 
-        // Validate no path
+        // Validate unreachable vertices
+        let tree = graph.shortest_paths_from(&"d".to_string())?;
+        assert!(!tree.is_reachable(&"a".to_string()));
+        assert_eq!(tree.distance_to(&"a".to_string()), None);
+        assert_eq!(tree.path_to(&"a".to_string()), None);
+
+        // Validate unknown source
+        assert_eq!(
+            graph.shortest_paths_from(&"Montreal".to_string()).err(),
+            Some(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_star() -> Result<(), GraphError<String>> {
+        let graph = build_cities_graph()?;
+
+        // Without estimation, A* finds the same paths than Dijkstra's algorithm
+        assert_eq!(
+            graph.a_star(&"a".to_string(), &"e".to_string(), |_| 0),
+            graph.shortest_path(&"a".to_string(), &"e".to_string())
+        );
         assert_eq!(
-            graph.shortest_path("b".to_string(), "a".to_string()),
-            Ok(vec![])
+            graph.a_star(&"b".to_string(), &"a".to_string(), |_| 0),
+            Ok(None)
         );
 
         Ok(())
@@ -0,0 +1,385 @@
+use std::{
+    cmp,
+    collections::{BinaryHeap, HashMap, HashSet},
+};
+
+use super::error::GraphError;
+use super::graph_trait::{Graph, VertexId};
+use super::weight::Weight;
+
+/// A path between two vertices, and its total weight
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<Id, W = u32> {
+    /// Vertices of the path, from the source to the target
+    pub vertices: Vec<Id>,
+
+    /// Sum of the weights of the edges of the path
+    pub total_weight: W,
+}
+
+/// Shortest paths from a source vertex to every vertex it can reach
+///
+/// It stores, for each reachable vertex, its distance from the source and
+/// the previous vertex on the shortest path (its predecessor), so the
+/// shortest path to any vertex can be rebuilt without running the
+/// algorithm again.
+#[derive(Debug, Clone)]
+pub struct ShortestPathTree<Id, W = u32> {
+    /// Vertex the paths start from
+    pub source: Id,
+
+    /// Shortest distance between the source and each reachable vertex
+    pub distances: HashMap<Id, W>,
+
+    /// Previous vertex on the shortest path from the source to each
+    /// reachable vertex (the source itself has no predecessor)
+    ///
+    /// Note: this is a linked list going backward, from a vertex to the source
+    pub predecessors: HashMap<Id, Id>,
+}
+
+impl<Id: std::hash::Hash + Eq + Clone, W: Weight> ShortestPathTree<Id, W> {
+    /// Denote if a vertex can be reached from the source
+    pub fn is_reachable(&self, target_id: &Id) -> bool {
+        self.distances.contains_key(target_id)
+    }
+
+    /// Return the total cost of the shortest path from the source to a vertex,
+    /// or None if it can not be reached
+    pub fn distance_to(&self, target_id: &Id) -> Option<W> {
+        self.distances.get(target_id).copied()
+    }
+
+    /// Return the previous vertex on the shortest path from the source to a vertex
+    pub fn predecessor(&self, target_id: &Id) -> Option<&Id> {
+        self.predecessors.get(target_id)
+    }
+
+    /// Return the shortest path from the source to a vertex,
+    /// or None if it can not be reached
+    pub fn path_to(&self, target_id: &Id) -> Option<Path<Id, W>> {
+        let total_weight = self.distance_to(target_id)?;
+
+        // Build the path to target vertex by linearize
+        // the `predecessors` hashmap
+        let mut result = vec![target_id.clone()];
+        let mut curr_id = target_id;
+        while let Some(next_id) = self.predecessors.get(curr_id) {
+            result.push(next_id.clone());
+            curr_id = next_id;
+        }
+
+        // Reverse the result, as it first come backward
+        result.reverse();
+
+        Some(Path {
+            vertices: result,
+            total_weight,
+        })
+    }
+}
+
+/// Result of the single source shortest paths algorithms
+pub type ShortestPathsResult<G> =
+    Result<ShortestPathTree<VertexId<G>, <G as Graph>::Weight>, GraphError<VertexId<G>>>;
+
+/// Result of the shortest path algorithms between two vertices
+pub type PathResult<G> =
+    Result<Option<Path<VertexId<G>, <G as Graph>::Weight>>, GraphError<VertexId<G>>>;
+
+/// Return the shortest path between two vertices of the graph,
+/// or None if the target can not be reached from the source
+///
+/// This function uses [Dijkstra's shortest path algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm),
+/// and stops as soon as the target vertex is reached.
+/// Negative weights are not allowed (see `bellman_ford` instead): it Errs on
+/// the first negative edge it explores, but the edges that are not explored
+/// before the target is reached are not checked.
+/// It Errs with `WeightOverflow` if the target can only be reached by paths
+/// whose weight does not fit in the weight type.
+pub fn shortest_path<G: Graph>(
+    graph: &G,
+    source_id: &VertexId<G>,
+    target_id: &VertexId<G>,
+) -> PathResult<G>
+where
+    G::Weight: Weight,
+{
+    // Check that target exists, or err
+    graph.vertex_exists_or_err(target_id)?;
+
+    let (tree, _) = dijkstra(graph, source_id, Some(target_id), |_, _, weight| {
+        Some(weight)
+    })?;
+
+    Ok(tree.path_to(target_id))
+}
+
+/// Return the shortest paths from a source vertex to every vertex it can reach
+///
+/// Unlike `shortest_path`, the whole graph is explored, so the result
+/// can answer the queries to any target vertex.
+/// This function uses [Dijkstra's shortest path algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm).
+/// It Errs if an edge reachable from the source has a negative weight
+/// (see `bellman_ford` instead), or with `WeightOverflow` if a vertex can
+/// only be reached by paths whose weight does not fit in the weight type.
+pub fn shortest_paths_from<G: Graph>(graph: &G, source_id: &VertexId<G>) -> ShortestPathsResult<G>
+where
+    G::Weight: Weight,
+{
+    dijkstra(graph, source_id, None, |_, _, weight| Some(weight)).map(|(tree, _)| tree)
+}
+
+/// Check that the weight of an edge explored by Dijkstra's or A* algorithms
+/// is not negative, or Err
+///
+/// These algorithms never visit a vertex twice, so a negative edge found
+/// later could lead to a shorter path than the one already chosen.
+pub(crate) fn non_negative_weight_or_err<Id: Clone, W: Weight>(
+    from: &Id,
+    to: &Id,
+    weight: W,
+) -> Result<(), GraphError<Id>> {
+    match weight < W::zero() {
+        true => Err(GraphError::NegativeWeight {
+            from: from.clone(),
+            to: to.clone(),
+        }),
+        false => Ok(()),
+    }
+}
+
+/// Return a `WeightOverflow` error for the first of the edges leading to
+/// paths too long for the weight type whose target was not reached otherwise
+pub(crate) fn overflows_or_ok<Id: std::hash::Hash + Eq, W>(
+    distances: &HashMap<Id, W>,
+    overflowing_edges: Vec<(Id, Id)>,
+) -> Result<(), GraphError<Id>> {
+    match overflowing_edges
+        .into_iter()
+        .find(|(_, to)| !distances.contains_key(to))
+    {
+        Some((from, to)) => Err(GraphError::WeightOverflow { from, to }),
+        None => Ok(()),
+    }
+}
+
+/// Result of Dijkstra's algorithm: the shortest path tree, and the number
+/// of vertices that were explored to build it
+pub(crate) type DijkstraResult<G> =
+    Result<(ShortestPathTree<VertexId<G>, <G as Graph>::Weight>, usize), GraphError<VertexId<G>>>;
+
+/// Run Dijkstra's algorithm from a source vertex, and return the shortest
+/// path tree along with the number of explored vertices
+///
+/// Note: it Errs on the first negative weight it explores
+/// (see `non_negative_weight_or_err`)
+///
+/// If a target is given, the exploration stops as soon as the target is reached,
+/// so only the path to the target is guaranteed to be complete.
+/// It Errs with `WeightOverflow` if a vertex (the target, if any) can only be
+/// reached by paths whose weight does not fit in the weight type.
+///
+/// `edge_weight` maps the weight of an edge (from, to, weight) to the weight
+/// used by the algorithm (None if it overflows), which allows to run it on
+/// reweighted edges without building a new graph (see Johnson's algorithm).
+pub(crate) fn dijkstra<G: Graph>(
+    graph: &G,
+    source_id: &VertexId<G>,
+    target_id: Option<&VertexId<G>>,
+    edge_weight: impl Fn(&VertexId<G>, &VertexId<G>, G::Weight) -> Option<G::Weight>,
+) -> DijkstraResult<G>
+where
+    G::Weight: Weight,
+{
+    // Check that source exists, or err
+    graph.vertex_exists_or_err(source_id)?;
+
+    // Keep track of the vertices visited in the main loop
+    let mut seen_vertices = HashSet::new();
+    // Keep track of the shorter distance between source and a given vertex
+    let mut shortest_distances_from_source = HashMap::new();
+    // Keep track of the shortest ways between source and a given vertex
+    // (This is stored as a linked list)
+    let mut path = HashMap::new();
+    // The priority queue is used to iterate step by step between vertices
+    // while always priorising the known vertex which is the closest from the
+    // source node
+    let mut priority_queue = BinaryHeap::new();
+    // Keep track of the edges leading to paths too long for the weight type
+    let mut overflowing_edges = Vec::new();
+
+    // Initialize the state with the knowledge we have
+    // - The distance between source and itself is 0
+    // - Source is the closes node we know
+    shortest_distances_from_source.insert(source_id.clone(), G::Weight::zero());
+    priority_queue.push(VertexByDistance {
+        id: source_id.clone(),
+        distance: G::Weight::zero(),
+    });
+
+    // Loop through the vertices by "closest from the source" order
+    while let Some(VertexByDistance {
+        id: current_id,
+        distance: source_current_distance,
+    }) = priority_queue.pop()
+    {
+        // A vertex can be queued several times before being visited,
+        // only its first (and shortest) visit is relevant
+        if !seen_vertices.insert(current_id.clone()) {
+            continue;
+        }
+
+        // If we reach the target vertex, we stop
+        if target_id == Some(&current_id) {
+            break;
+        }
+
+        // Else, for each neighbor, check if the current path is shorter
+        // than a path explored previously, and update the state
+        for (neighbor_id, current_neighbor_distance) in graph.neighbors(&current_id)? {
+            // distance from source->neighbor = distance from source->current + current->neighbor
+            // Note: an overflowing path only matters if the neighbor is not
+            // reached by a shorter one
+            let weight = edge_weight(&current_id, neighbor_id, *current_neighbor_distance);
+            if let Some(weight) = weight {
+                non_negative_weight_or_err(&current_id, neighbor_id, weight)?;
+            }
+
+            // Ignore neighbor vertices that have already been visited
+            if seen_vertices.contains(neighbor_id) {
+                continue;
+            }
+
+            let Some(source_neighbor_distance) =
+                weight.and_then(|weight| weight.checked_add(source_current_distance))
+            else {
+                overflowing_edges.push((current_id.clone(), neighbor_id.clone()));
+                continue;
+            };
+
+            // An unknown distance is considered infinite
+            let is_shorter = match shortest_distances_from_source.get(neighbor_id) {
+                Some(known_distance) => source_neighbor_distance < *known_distance,
+                None => true,
+            };
+
+            if is_shorter {
+                // Update shortest distance from source, as a shorter path was found
+                shortest_distances_from_source
+                    .insert(neighbor_id.clone(), source_neighbor_distance);
+
+                // Update path with the shortest path that was found
+                path.insert(neighbor_id.clone(), current_id.clone());
+
+                // Insert the newly visited vertex in the priority queue
+                // The next closer vertex from the source will be visited first
+                priority_queue.push(VertexByDistance {
+                    id: neighbor_id.clone(),
+                    distance: source_neighbor_distance,
+                });
+            }
+        }
+    }
+
+    // The target, or every vertex, must not only be reached by overflowing paths
+    match target_id {
+        Some(target_id) if shortest_distances_from_source.contains_key(target_id) => (),
+        _ => overflows_or_ok(&shortest_distances_from_source, overflowing_edges)?,
+    }
+
+    let explored_vertices_count = seen_vertices.len();
+    let tree = ShortestPathTree {
+        source: source_id.clone(),
+        distances: shortest_distances_from_source,
+        predecessors: path,
+    };
+
+    Ok((tree, explored_vertices_count))
+}
+
+/// Type wrapper that implements Ord and Eq
+///
+/// It is used to be able to compare vertices by distance and
+/// sort them with a BinaryHeap priority queue
+pub(crate) struct VertexByDistance<Id, W> {
+    pub(crate) id: Id,
+    pub(crate) distance: W,
+}
+
+impl<Id, W: Ord> PartialEq for VertexByDistance<Id, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance == other.distance
+    }
+}
+
+impl<Id, W: Ord> PartialOrd for VertexByDistance<Id, W> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Id, W: Ord> Ord for VertexByDistance<Id, W> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.distance.cmp(&other.distance).reverse()
+    }
+}
+
+impl<Id, W: Ord> Eq for VertexByDistance<Id, W> {}
+
+#[cfg(test)]
+mod test {
+    use super::super::graph_trait::GraphMut;
+    use super::super::oriented_weighted_graph::OrientedWeightedGraph;
+    use super::super::undirected_weighted_graph::UndirectedWeightedGraph;
+    use super::*;
+
+    #[test]
+    fn test_shortest_paths_on_undirected_graph() -> Result<(), GraphError<u32>> {
+        let mut graph: UndirectedWeightedGraph<u32> = UndirectedWeightedGraph::new();
+
+        for id in 0..4 {
+            graph.add_vertex(id)?;
+        }
+        graph.add_edge(&0, &1, 5)?;
+        graph.add_edge(&1, &2, 1)?;
+        graph.add_edge(&0, &2, 2)?;
+
+        // Edges can be walked in both directions
+        let expected_path = Path {
+            vertices: vec![1, 2, 0],
+            total_weight: 3,
+        };
+        assert_eq!(shortest_path(&graph, &1, &0)?, Some(expected_path.clone()));
+        assert_eq!(shortest_path(&graph, &1, &3)?, None);
+
+        let tree = shortest_paths_from(&graph, &1)?;
+        assert_eq!(tree.distance_to(&0), Some(3));
+        assert_eq!(tree.path_to(&0), Some(expected_path));
+        assert!(!tree.is_reachable(&3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_weights() -> Result<(), GraphError<u32>> {
+        // The path through 2 is shorter than the direct edge, thanks to the negative edge
+        let mut graph: OrientedWeightedGraph<u32, i32> = OrientedWeightedGraph::new();
+        for id in 0..4 {
+            graph.add_vertex(id)?;
+        }
+        graph.add_edge(&0, &1, 5)?;
+        graph.add_edge(&0, &2, 2)?;
+        graph.add_edge(&2, &1, -5)?;
+        graph.add_edge(&0, &3, 1)?;
+
+        let error = GraphError::NegativeWeight { from: 2, to: 1 };
+        assert_eq!(shortest_path(&graph, &0, &1), Err(error.clone()));
+        assert_eq!(shortest_paths_from(&graph, &0).err(), Some(error));
+
+        // The search stops before exploring the negative edge
+        assert_eq!(shortest_path(&graph, &0, &3)?.unwrap().total_weight, 1);
+
+        Ok(())
+    }
+}
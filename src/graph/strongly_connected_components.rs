@@ -0,0 +1,286 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use super::graph_trait::{graph_neighbors, Graph, GraphMut, VertexId};
+use super::oriented_graph::OrientedGraph;
+
+/// Strongly connected components of an oriented graph
+///
+/// Two vertices are in the same component when each one can be reached
+/// from the other. The components are sorted in topological order:
+/// the edges between two components always go from a component to a
+/// component placed after it.
+#[derive(Debug, Clone)]
+pub struct StronglyConnectedComponents<Id> {
+    /// Vertex ids of each component
+    pub components: Vec<Vec<Id>>,
+
+    /// Index of the component of each vertex
+    pub membership: HashMap<Id, usize>,
+}
+
+impl<Id: Hash + Eq + Clone> StronglyConnectedComponents<Id> {
+    /// Return the number of components
+    pub fn component_count(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Return the index of the component of a vertex,
+    /// or None if it is not in the graph
+    pub fn component_of(&self, id: &Id) -> Option<usize> {
+        self.membership.get(id).copied()
+    }
+
+    /// Denote if two vertices are in the same component
+    pub fn are_strongly_connected(&self, id1: &Id, id2: &Id) -> bool {
+        match (self.component_of(id1), self.component_of(id2)) {
+            (Some(component1), Some(component2)) => component1 == component2,
+            _ => false,
+        }
+    }
+}
+
+/// Return the strongly connected components of the graph
+///
+/// This function uses [Tarjan's algorithm](https://en.wikipedia.org/wiki/Tarjan%27s_strongly_connected_components_algorithm).
+/// It explores the graph depth first, and keeps the explored vertices on
+/// a stack until the root of their component (the first vertex of the
+/// component that was discovered) is finished.
+pub fn strongly_connected_components<G: Graph>(
+    graph: &G,
+) -> StronglyConnectedComponents<VertexId<G>> {
+    let mut state = TarjanState::new();
+
+    for start_id in graph.vertex_ids() {
+        if state.indices.contains_key(start_id) {
+            continue;
+        }
+
+        // Stack of the vertices being explored, with the neighbors
+        // left to explore for each of them
+        state.discover(start_id);
+        let mut neighbors_stack = vec![(start_id, graph_neighbors(graph, start_id))];
+
+        while let Some((vertex_id, neighbors)) = neighbors_stack.last_mut() {
+            let vertex_id = *vertex_id;
+
+            match neighbors.next() {
+                Some((neighbor_id, _)) => {
+                    if !state.indices.contains_key(neighbor_id) {
+                        // Not discovered yet: go deeper
+                        state.discover(neighbor_id);
+                        neighbors_stack.push((neighbor_id, graph_neighbors(graph, neighbor_id)));
+                    } else if state.on_stack.contains(neighbor_id) {
+                        // Discovered and still on the stack: it is in the component
+                        // being explored, and may be reached before the current vertex
+                        state.lower_low_link(vertex_id, state.indices[neighbor_id]);
+                    }
+                }
+                None => {
+                    // All the neighbors were explored, go back to the parent
+                    neighbors_stack.pop();
+                    if let Some((parent_id, _)) = neighbors_stack.last() {
+                        state.lower_low_link(parent_id, state.low_links[vertex_id]);
+                    }
+
+                    // A vertex that can not reach an older vertex of the stack
+                    // is the root of a component made of the vertices above it
+                    if state.low_links[vertex_id] == state.indices[vertex_id] {
+                        state.pop_component(vertex_id);
+                    }
+                }
+            }
+        }
+    }
+
+    // Tarjan's algorithm finds the components in reverse topological order
+    let mut components = state.components;
+    components.reverse();
+
+    let membership = components
+        .iter()
+        .enumerate()
+        .flat_map(|(index, component)| component.iter().map(move |id| (id.clone(), index)))
+        .collect();
+
+    StronglyConnectedComponents {
+        components,
+        membership,
+    }
+}
+
+/// Return the condensation of the graph: a graph whose vertices are
+/// its strongly connected components
+///
+/// Each vertex is identified by the index of its component, and carries
+/// the ids of the component vertices. An edge links two components when
+/// an edge links two of their vertices, so the condensation never has cycles.
+pub fn condensation<G: Graph>(graph: &G) -> OrientedGraph<(usize, Vec<VertexId<G>>)> {
+    let StronglyConnectedComponents {
+        components,
+        membership,
+    } = strongly_connected_components(graph);
+
+    let mut condensation = OrientedGraph::new();
+
+    // Note: the component indices are unique and the edges are only added
+    // once, so the graph updates can not Err
+    for component in components.into_iter().enumerate() {
+        condensation.add_vertex(component).unwrap();
+    }
+
+    for vertex_id in graph.vertex_ids() {
+        let component = membership[vertex_id];
+
+        for (neighbor_id, _) in graph_neighbors(graph, vertex_id) {
+            let neighbor_component = membership[neighbor_id];
+
+            // Edges inside a component disappear
+            if component != neighbor_component
+                && !condensation.edge_exists(&component, &neighbor_component)
+            {
+                condensation
+                    .add_edge(&component, &neighbor_component)
+                    .unwrap();
+            }
+        }
+    }
+
+    condensation
+}
+
+/// State of Tarjan's algorithm
+struct TarjanState<'a, Id> {
+    /// Index of the next discovered vertex
+    next_index: usize,
+
+    /// Discovery index of each vertex
+    indices: HashMap<&'a Id, usize>,
+
+    /// Smallest discovery index of the vertices of the stack
+    /// that can be reached from each vertex
+    low_links: HashMap<&'a Id, usize>,
+
+    /// Vertices whose component was not found yet
+    stack: Vec<&'a Id>,
+    on_stack: HashSet<&'a Id>,
+
+    /// Components found so far
+    components: Vec<Vec<Id>>,
+}
+
+impl<'a, Id: Hash + Eq + Clone> TarjanState<'a, Id> {
+    fn new() -> Self {
+        Self {
+            next_index: 0,
+            indices: HashMap::new(),
+            low_links: HashMap::new(),
+            stack: Vec::new(),
+            on_stack: HashSet::new(),
+            components: Vec::new(),
+        }
+    }
+
+    /// Give its discovery index to a vertex, and push it on the stack
+    fn discover(&mut self, id: &'a Id) {
+        self.indices.insert(id, self.next_index);
+        self.low_links.insert(id, self.next_index);
+        self.next_index += 1;
+
+        self.stack.push(id);
+        self.on_stack.insert(id);
+    }
+
+    /// Update the low link of a vertex if the given index is lower
+    fn lower_low_link(&mut self, id: &'a Id, index: usize) {
+        let low_link = self.low_links.get_mut(id).unwrap();
+        *low_link = (*low_link).min(index);
+    }
+
+    /// Pop the vertices of the stack down to the root of their component
+    fn pop_component(&mut self, root_id: &Id) {
+        let mut component = Vec::new();
+
+        while let Some(id) = self.stack.pop() {
+            self.on_stack.remove(id);
+            component.push(id.clone());
+
+            if id == root_id {
+                break;
+            }
+        }
+
+        self.components.push(component);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::error::GraphError;
+    use super::super::test_utils::build_oriented_graph;
+    use super::*;
+
+    /// Two cycles (a, b, c) and (d, e) linked by c -> d, and f alone
+    fn build_cycles_graph() -> Result<OrientedGraph<&'static str>, GraphError<&'static str>> {
+        build_oriented_graph(
+            &["a", "b", "c", "d", "e", "f"],
+            &[
+                ("a", "b"),
+                ("b", "c"),
+                ("c", "a"),
+                ("c", "d"),
+                ("d", "e"),
+                ("e", "d"),
+                ("e", "f"),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_strongly_connected_components() -> Result<(), GraphError<&'static str>> {
+        let graph = build_cycles_graph()?;
+
+        let components = graph.strongly_connected_components();
+
+        assert_eq!(components.component_count(), 3);
+        assert!(components.are_strongly_connected(&"a", &"c"));
+        assert!(components.are_strongly_connected(&"d", &"e"));
+        assert!(!components.are_strongly_connected(&"c", &"d"));
+        assert!(!components.are_strongly_connected(&"a", &"z"));
+
+        // Components come in topological order
+        let mut sorted_components = components.components.clone();
+        for component in sorted_components.iter_mut() {
+            component.sort();
+        }
+        assert_eq!(
+            sorted_components,
+            vec![vec!["a", "b", "c"], vec!["d", "e"], vec!["f"]]
+        );
+        assert_eq!(components.component_of(&"f"), Some(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_condensation() -> Result<(), GraphError<&'static str>> {
+        let graph = build_cycles_graph()?;
+
+        let condensation = graph.condensation();
+
+        assert_eq!(condensation.vertex_count(), 3);
+        assert_eq!(condensation.edge_count(), 2);
+        assert!(condensation.edge_exists(&0, &1));
+        assert!(condensation.edge_exists(&1, &2));
+
+        // Vertices carry the ids of the component vertices
+        let mut component = condensation.vertex(&1).unwrap().1.clone();
+        component.sort();
+        assert_eq!(component, vec!["d", "e"]);
+
+        // The condensation can be sorted
+        assert_eq!(condensation.topological_order(), Ok(vec![0, 1, 2]));
+
+        Ok(())
+    }
+}
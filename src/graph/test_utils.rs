@@ -0,0 +1,131 @@
+//! Fixtures shared by the tests of the graph algorithms
+
+use std::fmt::Debug;
+
+use super::error::GraphError;
+use super::graph_trait::{Graph, GraphMut};
+use super::identifiable::Identifiable;
+use super::oriented_graph::OrientedGraph;
+use super::oriented_weighted_graph::OrientedWeightedGraph;
+use super::undirected_graph::UndirectedGraph;
+
+/// Basic vertex for testing purpose, identified by its name
+#[derive(PartialEq, Clone, Debug)]
+pub(crate) struct City {
+    pub name: String,
+}
+
+impl Identifiable for City {
+    type Id = String;
+
+    fn get_id(&self) -> &String {
+        &self.name
+    }
+}
+
+/// Build a city from its name
+pub(crate) fn city(name: &str) -> City {
+    City {
+        name: name.to_string(),
+    }
+}
+
+/// Add cities, from their names, and the edges between them into a graph
+pub(crate) fn add_cities<G: GraphMut<Vertex = City>>(
+    graph: &mut G,
+    names: &[&str],
+    edges: &[(&str, &str, G::Weight)],
+) -> Result<(), GraphError<String>>
+where
+    G::Weight: Clone,
+{
+    for name in names {
+        graph.add_vertex(city(name))?;
+    }
+    for (from, to, weight) in edges {
+        graph.add_edge(&from.to_string(), &to.to_string(), weight.clone())?;
+    }
+
+    Ok(())
+}
+
+/// Build an oriented graph from its vertices and its edges
+pub(crate) fn build_oriented_graph(
+    vertices: &[&'static str],
+    edges: &[(&'static str, &'static str)],
+) -> Result<OrientedGraph<&'static str>, GraphError<&'static str>> {
+    let mut graph = OrientedGraph::new();
+
+    for vertex in vertices {
+        graph.add_vertex(*vertex)?;
+    }
+    for (from, to) in edges {
+        graph.add_edge(from, to)?;
+    }
+
+    Ok(graph)
+}
+
+/// Build an undirected graph from its vertices and its edges
+pub(crate) fn build_undirected_graph(
+    vertices: &[&'static str],
+    edges: &[(&'static str, &'static str)],
+) -> Result<UndirectedGraph<&'static str>, GraphError<&'static str>> {
+    let mut graph = UndirectedGraph::new();
+
+    for vertex in vertices {
+        graph.add_vertex(*vertex)?;
+    }
+    for (from, to) in edges {
+        graph.add_edge(from, to)?;
+    }
+
+    Ok(graph)
+}
+
+/// Build an oriented weighted graph from its vertices and its edges
+pub(crate) fn build_weighted_graph<W: Copy>(
+    vertices: &[&'static str],
+    edges: &[(&'static str, &'static str, W)],
+) -> Result<OrientedWeightedGraph<&'static str, W>, GraphError<&'static str>> {
+    let mut graph = OrientedWeightedGraph::new();
+
+    for vertex in vertices {
+        graph.add_vertex(*vertex)?;
+    }
+    for (from, to, weight) in edges {
+        graph.add_edge(from, to, *weight)?;
+    }
+
+    Ok(graph)
+}
+
+/// Build an oriented weighted graph from its edges, the vertices being
+/// the ends of the edges
+pub(crate) fn build_weighted_graph_from_edges<W: Copy>(
+    edges: &[(&'static str, &'static str, W)],
+) -> Result<OrientedWeightedGraph<&'static str, W>, GraphError<&'static str>> {
+    let mut graph = OrientedWeightedGraph::new();
+
+    for (from, to, weight) in edges {
+        for id in [from, to] {
+            if !graph.vertex_exists(id) {
+                graph.add_vertex(*id)?;
+            }
+        }
+        graph.add_edge(from, to, *weight)?;
+    }
+
+    Ok(graph)
+}
+
+/// Rotate a cycle so it starts from the given vertex, as the algorithms
+/// can return a cycle starting from any of its vertices
+pub(crate) fn rotate_cycle<Id: PartialEq + Debug>(mut cycle: Vec<Id>, first: &Id) -> Vec<Id> {
+    match cycle.iter().position(|id| id == first) {
+        Some(index) => cycle.rotate_left(index),
+        None => panic!("{:?} is not in the cycle {:?}", first, cycle),
+    }
+
+    cycle
+}
@@ -0,0 +1,174 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use super::error::GraphError;
+use super::graph_trait::{graph_neighbors, Graph, VertexId};
+
+/// Return the vertex ids ordered so that every edge goes from a vertex
+/// to a vertex placed after it, or a `Cycle` error if there is none
+///
+/// This function uses [Kahn's algorithm](https://en.wikipedia.org/wiki/Topological_sorting#Kahn's_algorithm):
+/// vertices without incoming edges are placed first, then removed from the
+/// graph along with their edges, until all the vertices are placed.
+/// When several vertices could be placed, the smallest id comes first,
+/// so the order is always the same for a given graph.
+///
+/// Note: edges are considered oriented, so the edges of undirected graphs
+/// are seen as cycles between their two vertices
+pub fn topological_order<G: Graph>(graph: &G) -> Result<Vec<VertexId<G>>, GraphError<VertexId<G>>>
+where
+    VertexId<G>: Ord,
+{
+    // Count the incoming edges of each vertex
+    let mut in_degrees: HashMap<&VertexId<G>, usize> =
+        graph.vertex_ids().map(|vertex_id| (vertex_id, 0)).collect();
+    for vertex_id in graph.vertex_ids() {
+        for (neighbor_id, _) in graph.neighbors(vertex_id)? {
+            *in_degrees.get_mut(neighbor_id).unwrap() += 1;
+        }
+    }
+
+    // The vertices without incoming edges are ready to be placed,
+    // and are sorted so that the smallest id is placed first
+    let mut ready_vertices: BinaryHeap<_> = in_degrees
+        .iter()
+        .filter(|(_, in_degree)| **in_degree == 0)
+        .map(|(vertex_id, _)| Reverse(*vertex_id))
+        .collect();
+
+    let mut order = Vec::with_capacity(graph.vertex_count());
+
+    while let Some(Reverse(vertex_id)) = ready_vertices.pop() {
+        order.push(vertex_id.clone());
+
+        // Removing the edges from the placed vertex can make its neighbors ready
+        for (neighbor_id, _) in graph.neighbors(vertex_id)? {
+            let in_degree = in_degrees.get_mut(neighbor_id).unwrap();
+            *in_degree -= 1;
+            if *in_degree == 0 {
+                ready_vertices.push(Reverse(neighbor_id));
+            }
+        }
+    }
+
+    // The vertices of a cycle always have an incoming edge,
+    // so they can never be placed
+    if order.len() < graph.vertex_count() {
+        let cycle = find_cycle(graph).unwrap();
+        return Err(GraphError::Cycle(cycle));
+    }
+
+    Ok(order)
+}
+
+/// Denote if the graph contains a cycle
+pub fn has_cycle<G: Graph>(graph: &G) -> bool {
+    find_cycle(graph).is_some()
+}
+
+/// Return the vertices of a cycle of the graph, in the order of the cycle
+/// edges, or None if the graph has no cycle
+///
+/// It explores the graph depth first, keeping track of the path from the
+/// exploration start to the current vertex: a cycle is found when an
+/// edge leads back to a vertex of this path.
+pub fn find_cycle<G: Graph>(graph: &G) -> Option<Vec<VertexId<G>>> {
+    // Keep track of the vertices whose descendants were all explored
+    // (no cycle can be found through them anymore)
+    let mut explored_vertices = HashSet::new();
+
+    for start_id in graph.vertex_ids() {
+        if explored_vertices.contains(start_id) {
+            continue;
+        }
+
+        // Path from the start vertex to the current vertex, with the
+        // neighbors left to explore for each vertex of the path
+        let mut path = vec![start_id];
+        let mut path_vertices = HashSet::from([start_id]);
+        let mut neighbors_stack = vec![graph_neighbors(graph, start_id)];
+
+        while let Some(neighbors) = neighbors_stack.last_mut() {
+            match neighbors.next() {
+                Some((neighbor_id, _)) => {
+                    // Going back to a vertex of the path closes a cycle
+                    if path_vertices.contains(neighbor_id) {
+                        let cycle_start = path.iter().position(|id| *id == neighbor_id).unwrap();
+                        return Some(path[cycle_start..].iter().map(|id| (*id).clone()).collect());
+                    }
+
+                    // Else go deeper
+                    if !explored_vertices.contains(neighbor_id) {
+                        path.push(neighbor_id);
+                        path_vertices.insert(neighbor_id);
+                        neighbors_stack.push(graph_neighbors(graph, neighbor_id));
+                    }
+                }
+                None => {
+                    // All the neighbors were explored, go back
+                    neighbors_stack.pop();
+                    let vertex_id = path.pop().unwrap();
+                    path_vertices.remove(vertex_id);
+                    explored_vertices.insert(vertex_id);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::{build_oriented_graph, rotate_cycle};
+    use super::*;
+
+    #[test]
+    fn test_topological_order() -> Result<(), GraphError<&'static str>> {
+        // Build tasks: compiling depends on fetching, and so on
+        let graph = build_oriented_graph(
+            &["test", "fetch", "compile", "lint", "release", "docs"],
+            &[
+                ("fetch", "compile"),
+                ("fetch", "lint"),
+                ("compile", "test"),
+                ("lint", "release"),
+                ("test", "release"),
+            ],
+        )?;
+
+        // When several tasks are ready, they are sorted by id
+        assert_eq!(
+            graph.topological_order(),
+            Ok(vec!["docs", "fetch", "compile", "lint", "test", "release"])
+        );
+        assert!(!graph.has_cycle());
+        assert_eq!(graph.find_cycle(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cycle() -> Result<(), GraphError<&'static str>> {
+        let graph = build_oriented_graph(
+            &["a", "b", "c", "d"],
+            &[("a", "b"), ("b", "c"), ("c", "d"), ("d", "b")],
+        )?;
+
+        assert!(graph.has_cycle());
+
+        match graph.topological_order() {
+            Err(GraphError::Cycle(cycle)) => {
+                assert_eq!(rotate_cycle(cycle, &"b"), vec!["b", "c", "d"]);
+            }
+            result => panic!("Expected a cycle, got {:?}", result),
+        }
+
+        // A self loop is a cycle
+        let graph = build_oriented_graph(&["a", "b"], &[("a", "b"), ("b", "b")])?;
+        assert_eq!(graph.find_cycle(), Some(vec!["b"]));
+        assert_eq!(graph.topological_order(), Err(GraphError::Cycle(vec!["b"])));
+
+        Ok(())
+    }
+}
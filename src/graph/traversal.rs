@@ -0,0 +1,217 @@
+use std::collections::{HashSet, VecDeque};
+use std::iter;
+
+use super::error::GraphError;
+use super::graph_trait::{graph_neighbors, Graph, VertexId};
+use super::identifiable::Identifiable;
+
+/// A vertex reached by a traversal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Visit<'a, V, Id> {
+    /// The reached vertex
+    pub vertex: &'a V,
+
+    /// Number of edges between the start vertex and the reached vertex
+    pub depth: usize,
+
+    /// Id of the vertex it was reached from (None for the start vertex)
+    pub parent: Option<&'a Id>,
+}
+
+/// Type of the visits of the traversals of a graph
+pub type GraphVisit<'a, G> = Visit<'a, <G as Graph>::Vertex, VertexId<G>>;
+
+/// Iterate over the vertices reachable from a start vertex, breadth first,
+/// or Err if the start vertex does not exist
+///
+/// The vertices are only reached when the iterator is advanced, so the
+/// traversal can be stopped at any time (with `find`, `take_while`, ...).
+/// The depth of a vertex is its distance from the start vertex.
+pub fn bfs<'a, G: Graph + ?Sized>(
+    graph: &'a G,
+    start_id: &VertexId<G>,
+) -> Result<impl Iterator<Item = GraphVisit<'a, G>> + use<'a, G>, GraphError<VertexId<G>>> {
+    let start_id = graph
+        .vertex(start_id)
+        .ok_or_else(|| GraphError::VertexNotFound(start_id.clone()))?
+        .get_id();
+
+    // Vertices are marked as seen when queued, so they are queued only once
+    let mut seen = HashSet::from([start_id]);
+    let mut queue = VecDeque::from([(start_id, 0, None)]);
+
+    Ok(iter::from_fn(move || {
+        let (vertex_id, depth, parent) = queue.pop_front()?;
+
+        // Queue the unseen neighbors, one level deeper
+        for (neighbor_id, _) in graph_neighbors(graph, vertex_id) {
+            if seen.insert(neighbor_id) {
+                queue.push_back((neighbor_id, depth + 1, Some(vertex_id)));
+            }
+        }
+
+        Some(Visit {
+            vertex: graph.vertex(vertex_id).unwrap(),
+            depth,
+            parent,
+        })
+    }))
+}
+
+/// Iterate over the vertices reachable from a start vertex, depth first,
+/// or Err if the start vertex does not exist
+///
+/// The vertices are only reached when the iterator is advanced, so the
+/// traversal can be stopped at any time (with `find`, `take_while`, ...).
+/// The depth of a vertex is its depth in the depth first search tree.
+pub fn dfs<'a, G: Graph + ?Sized>(
+    graph: &'a G,
+    start_id: &VertexId<G>,
+) -> Result<impl Iterator<Item = GraphVisit<'a, G>> + use<'a, G>, GraphError<VertexId<G>>> {
+    let start = graph
+        .vertex(start_id)
+        .ok_or_else(|| GraphError::VertexNotFound(start_id.clone()))?;
+    let start_id = start.get_id();
+
+    let mut seen = HashSet::from([start_id]);
+    // Path from the start vertex to the current vertex, with the depth
+    // and the neighbors left to explore of each vertex
+    let mut stack = vec![(start_id, 0, graph_neighbors(graph, start_id))];
+    // The start vertex is the first visit
+    let mut start_visit = Some(Visit {
+        vertex: start,
+        depth: 0,
+        parent: None,
+    });
+
+    Ok(iter::from_fn(move || {
+        if let Some(visit) = start_visit.take() {
+            return Some(visit);
+        }
+
+        while let Some((vertex_id, depth, neighbors)) = stack.last_mut() {
+            let (vertex_id, depth) = (*vertex_id, *depth);
+
+            match neighbors.find(|(neighbor_id, _)| !seen.contains(neighbor_id)) {
+                // Go deeper, through the first unseen neighbor
+                Some((neighbor_id, _)) => {
+                    seen.insert(neighbor_id);
+                    stack.push((neighbor_id, depth + 1, graph_neighbors(graph, neighbor_id)));
+
+                    return Some(Visit {
+                        vertex: graph.vertex(neighbor_id).unwrap(),
+                        depth: depth + 1,
+                        parent: Some(vertex_id),
+                    });
+                }
+                // All the neighbors were explored, go back
+                None => {
+                    stack.pop();
+                }
+            }
+        }
+
+        None
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::oriented_graph::OrientedGraph;
+    use super::super::test_utils::{add_cities, City};
+    use super::*;
+
+    /// Build the graph Tokio -> Paris -> Vancouver -> Montreal,
+    /// with a shortcut Tokio -> Vancouver
+    fn build_graph() -> Result<OrientedGraph<City>, GraphError<String>> {
+        let mut graph = OrientedGraph::new();
+        add_cities(
+            &mut graph,
+            &["Tokio", "Paris", "Vancouver", "Montreal"],
+            &[
+                ("Tokio", "Paris", ()),
+                ("Paris", "Vancouver", ()),
+                ("Vancouver", "Montreal", ()),
+                ("Tokio", "Vancouver", ()),
+            ],
+        )?;
+
+        Ok(graph)
+    }
+
+    #[test]
+    fn test_bfs() -> Result<(), GraphError<String>> {
+        let graph = build_graph()?;
+        let tokio = "Tokio".to_string();
+
+        // Vertices come by order of distance from the start vertex
+        let visits: Vec<_> = graph
+            .bfs(&tokio)?
+            .map(|visit| (visit.vertex.name.as_str(), visit.depth))
+            .collect();
+        assert_eq!(visits[0], ("Tokio", 0));
+        assert_eq!(visits[3], ("Montreal", 2));
+
+        // The vertices can be processed as they are reached
+        let montreal = graph
+            .bfs(&tokio)?
+            .find(|visit| visit.vertex.name == "Montreal")
+            .unwrap();
+        assert_eq!(montreal.parent, Some(&"Vancouver".to_string()));
+
+        let close_vertices = graph.bfs(&tokio)?.take_while(|visit| visit.depth <= 1);
+        assert_eq!(close_vertices.count(), 3);
+
+        // Same order than the eager traversal
+        assert_eq!(
+            graph
+                .bfs(&tokio)?
+                .map(|visit| visit.vertex.name.clone())
+                .collect::<Vec<_>>(),
+            graph.traverse_breadth_first(&tokio)?
+        );
+
+        // Unknown vertex should Err
+        assert_eq!(
+            graph.bfs(&"Lyon".to_string()).err(),
+            Some(GraphError::VertexNotFound("Lyon".to_string()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_dfs() -> Result<(), GraphError<String>> {
+        let graph = build_graph()?;
+        let tokio = "Tokio".to_string();
+
+        // Each vertex is reached from the previous vertex of the current path
+        for visit in graph.dfs(&tokio)? {
+            match visit.parent {
+                Some(parent) => assert!(graph.edge_exists(parent, &visit.vertex.name)),
+                None => assert_eq!(visit.vertex.name, "Tokio"),
+            }
+        }
+
+        // Same order than the eager traversal
+        assert_eq!(
+            graph
+                .dfs(&tokio)?
+                .map(|visit| visit.vertex.name.clone())
+                .collect::<Vec<_>>(),
+            graph.traverse_depth_first_recursive(&tokio)?
+        );
+
+        // Montreal is the deepest vertex, whatever the path
+        let deepest = graph.dfs(&tokio)?.max_by_key(|visit| visit.depth).unwrap();
+        assert_eq!(deepest.vertex.name, "Montreal");
+
+        // Unknown vertex should Err
+        assert_eq!(
+            graph.dfs(&"Lyon".to_string()).err(),
+            Some(GraphError::VertexNotFound("Lyon".to_string()))
+        );
+
+        Ok(())
+    }
+}
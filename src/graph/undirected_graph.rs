@@ -0,0 +1,325 @@
+use std::collections::{HashMap, HashSet};
+
+use super::error::GraphError;
+use super::graph_trait::{Graph, GraphMut};
+use super::identifiable::Identifiable;
+
+/// A basic undirected graph implementation
+///
+/// It uses the same storage than `OrientedGraph`, but every edge is stored
+/// in both directions, so that A - B can be walked from A and from B.
+/// The symmetry is maintained by `add_edge`, `remove_edge` and `remove_vertex`.
+#[derive(Debug)]
+pub struct UndirectedGraph<T: Identifiable> {
+    /// List of the graph vertices
+    pub vertices: HashMap<T::Id, T>,
+
+    /// List of the graph edges
+    ///
+    /// Note: For vertices A and B, the edge A - B is stored as
+    /// the entries { A: [B], B: [A] }
+    pub edges: HashMap<T::Id, HashSet<T::Id>>,
+}
+
+impl<T: Identifiable> UndirectedGraph<T> {
+    /// Instantiate a new undirected graph
+    pub fn new() -> Self {
+        Self {
+            vertices: HashMap::new(),
+            edges: HashMap::new(),
+        }
+    }
+
+    /// Add an edge into the graph, and Err if it already exists, of if it targets invalid vertices
+    pub fn add_edge(
+        &mut self,
+        vertex1_id: &T::Id,
+        vertex2_id: &T::Id,
+    ) -> Result<(), GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(vertex1_id)?;
+        self.vertex_exists_or_err(vertex2_id)?;
+
+        // Insert the new edge in both directions
+        let result = self
+            .edges
+            .get_mut(vertex1_id)
+            .unwrap()
+            .insert(vertex2_id.clone());
+
+        match result {
+            true => {
+                self.edges
+                    .get_mut(vertex2_id)
+                    .unwrap()
+                    .insert(vertex1_id.clone());
+                Ok(())
+            }
+            false => Err(GraphError::EdgeAlreadyExists {
+                from: vertex1_id.clone(),
+                to: vertex2_id.clone(),
+            }),
+        }
+    }
+
+    /// Return the degree of a vertex (the number of edges touching it),
+    /// and Err if it does not exist
+    ///
+    /// Note: a self loop touches the vertex twice, so it counts for 2
+    pub fn degree(&self, id: &T::Id) -> Result<usize, GraphError<T::Id>> {
+        let neighbors = self
+            .edges
+            .get(id)
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))?;
+
+        match neighbors.contains(id) {
+            true => Ok(neighbors.len() + 1),
+            false => Ok(neighbors.len()),
+        }
+    }
+}
+
+impl<T: Identifiable> Default for UndirectedGraph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Identifiable> Graph for UndirectedGraph<T> {
+    type Vertex = T;
+    type Weight = ();
+
+    fn vertex(&self, id: &T::Id) -> Option<&T> {
+        self.vertices.get(id)
+    }
+
+    fn vertices(&self) -> impl Iterator<Item = &T> {
+        self.vertices.values()
+    }
+
+    fn vertex_ids(&self) -> impl Iterator<Item = &T::Id> {
+        self.vertices.keys()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        id: &T::Id,
+    ) -> Result<impl Iterator<Item = (&'a T::Id, &'a ())> + use<'a, T>, GraphError<T::Id>> {
+        self.edges
+            .get(id)
+            .map(|ids| ids.iter().map(|neighbor_id| (neighbor_id, &())))
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))
+    }
+
+    fn edge(&self, from: &T::Id, to: &T::Id) -> Option<&()> {
+        self.edges
+            .get(from)
+            .filter(|ids| ids.contains(to))
+            .map(|_| &())
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Return the number of edges in the graph
+    ///
+    /// Note: each edge is stored twice, except self loops
+    fn edge_count(&self) -> usize {
+        let stored_edges: usize = self.edges.values().map(|ids| ids.len()).sum();
+        let self_loops = self
+            .edges
+            .iter()
+            .filter(|(id, ids)| ids.contains(id))
+            .count();
+
+        (stored_edges + self_loops) / 2
+    }
+}
+
+impl<T: Identifiable> GraphMut for UndirectedGraph<T> {
+    /// Add a vertex into the graph, and Err if it already exists
+    fn add_vertex(&mut self, vertex: T) -> Result<(), GraphError<T::Id>> {
+        let vertex_id = vertex.get_id().clone();
+
+        // Err if vertex already exists
+        // Or insert the vertex in the graph
+        if self.vertex_exists(&vertex_id) {
+            Err(GraphError::VertexAlreadyExists(vertex_id))
+        } else {
+            self.vertices.insert(vertex_id.clone(), vertex);
+            self.edges.insert(vertex_id, HashSet::new());
+            Ok(())
+        }
+    }
+
+    fn add_edge(&mut self, from: &T::Id, to: &T::Id, _weight: ()) -> Result<(), GraphError<T::Id>> {
+        UndirectedGraph::add_edge(self, from, to)
+    }
+
+    /// Try to remove an edge in both directions and return an Err if it was not present
+    fn remove_edge(
+        &mut self,
+        vertex1_id: &T::Id,
+        vertex2_id: &T::Id,
+    ) -> Result<(), GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(vertex1_id)?;
+        self.vertex_exists_or_err(vertex2_id)?;
+
+        // Try to remove the value
+        let result = self.edges.get_mut(vertex1_id).unwrap().remove(vertex2_id);
+
+        // If the edge was present and removed, remove its other direction, else Err
+        if result {
+            self.edges.get_mut(vertex2_id).unwrap().remove(vertex1_id);
+            Ok(())
+        } else {
+            Err(GraphError::EdgeNotFound {
+                from: vertex1_id.clone(),
+                to: vertex2_id.clone(),
+            })
+        }
+    }
+
+    /// Try to remove a vertex and return an Err if it was not present
+    fn remove_vertex(&mut self, vertex_id: &T::Id) -> Result<(), GraphError<T::Id>> {
+        // Check that vertex exist, or err
+        self.vertex_exists_or_err(vertex_id)?;
+
+        // Remove edges from this vertex
+        let neighbors = self.edges.remove(vertex_id).unwrap();
+
+        // Remove edges to this vertex: as edges are symmetric,
+        // only its neighbors refer to it
+        for neighbor_id in neighbors {
+            if let Some(ids) = self.edges.get_mut(&neighbor_id) {
+                ids.remove(vertex_id);
+            }
+        }
+
+        // Remove the vertex
+        self.vertices.remove(vertex_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::{add_cities, City};
+    use super::*;
+
+    fn build_graph() -> Result<UndirectedGraph<City>, GraphError<String>> {
+        let mut graph = UndirectedGraph::new();
+        add_cities(
+            &mut graph,
+            &["Tokio", "Paris", "Vancouver"],
+            &[("Tokio", "Paris", ()), ("Tokio", "Vancouver", ())],
+        )?;
+
+        Ok(graph)
+    }
+
+    #[test]
+    fn test_add_edge() -> Result<(), GraphError<String>> {
+        let mut graph = build_graph()?;
+
+        // Edges should be walkable in both directions
+        assert!(graph.edge_exists(&"Tokio".to_string(), &"Paris".to_string()));
+        assert!(graph.edge_exists(&"Paris".to_string(), &"Tokio".to_string()));
+        assert_eq!(graph.edge_count(), 2);
+
+        // Adding an edge in the other direction should Err
+        assert_eq!(
+            graph.add_edge(&"Paris".to_string(), &"Tokio".to_string()),
+            Err(GraphError::EdgeAlreadyExists {
+                from: "Paris".to_string(),
+                to: "Tokio".to_string()
+            })
+        );
+
+        // Adding an edge with an absent city should Err
+        assert_eq!(
+            graph.add_edge(&"Paris".to_string(), &"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        // Self loops are counted once
+        graph.add_edge(&"Paris".to_string(), &"Paris".to_string())?;
+        assert_eq!(graph.edge_count(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_degree() -> Result<(), GraphError<String>> {
+        let mut graph = build_graph()?;
+
+        assert_eq!(graph.degree(&"Tokio".to_string()), Ok(2));
+        assert_eq!(graph.degree(&"Paris".to_string()), Ok(1));
+        assert_eq!(
+            graph.degree(&"Montreal".to_string()),
+            Err(GraphError::VertexNotFound("Montreal".to_string()))
+        );
+
+        graph.add_edge(&"Paris".to_string(), &"Paris".to_string())?;
+        assert_eq!(graph.degree(&"Paris".to_string()), Ok(3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_edge() -> Result<(), GraphError<String>> {
+        let mut graph = build_graph()?;
+
+        // Removing the edge from any side should remove both directions
+        graph.remove_edge(&"Paris".to_string(), &"Tokio".to_string())?;
+        assert!(!graph.edge_exists(&"Tokio".to_string(), &"Paris".to_string()));
+        assert!(!graph.edge_exists(&"Paris".to_string(), &"Tokio".to_string()));
+
+        assert_eq!(
+            graph.remove_edge(&"Tokio".to_string(), &"Paris".to_string()),
+            Err(GraphError::EdgeNotFound {
+                from: "Tokio".to_string(),
+                to: "Paris".to_string()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_vertex() -> Result<(), GraphError<String>> {
+        let mut graph = build_graph()?;
+
+        graph.remove_vertex(&"Tokio".to_string())?;
+        assert_eq!(graph.vertex_count(), 2);
+        assert_eq!(graph.edge_count(), 0);
+        assert_eq!(graph.degree(&"Paris".to_string()), Ok(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_traversals() -> Result<(), GraphError<String>> {
+        let graph = build_graph()?;
+
+        // Undirected edges allow to reach the whole graph from any vertex
+        assert_eq!(graph.traverse_breadth_first(&"Paris".to_string())?.len(), 3);
+        assert_eq!(
+            graph
+                .traverse_depth_first_iterative(&"Vancouver".to_string())?
+                .len(),
+            3
+        );
+        assert_eq!(
+            graph
+                .traverse_depth_first_recursive(&"Paris".to_string())?
+                .len(),
+            3
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use super::error::GraphError;
+use super::graph_trait::{Graph, GraphMut};
+use super::identifiable::Identifiable;
+
+/// This is the same Graph than `UndirectedGraph` except that
+/// it is weighted
+///
+/// The weights are of type `W`, `u32` by default
+#[derive(Debug)]
+pub struct UndirectedWeightedGraph<T: Identifiable, W = u32> {
+    /// List of the graph vertices
+    pub vertices: HashMap<T::Id, T>,
+
+    /// List of the graph edges
+    ///
+    /// Note: For vertices A and B, the edge A - B is stored as
+    /// the entries { A: { B: weight }, B: { A: weight } }
+    pub edges: HashMap<T::Id, HashMap<T::Id, W>>,
+}
+
+impl<T: Identifiable, W> UndirectedWeightedGraph<T, W> {
+    /// Instantiate a new graph
+    pub fn new() -> Self {
+        Self {
+            vertices: HashMap::new(),
+            edges: HashMap::new(),
+        }
+    }
+
+    /// Return the degree of a vertex (the number of edges touching it),
+    /// and Err if it does not exist
+    ///
+    /// Note: a self loop touches the vertex twice, so it counts for 2
+    pub fn degree(&self, id: &T::Id) -> Result<usize, GraphError<T::Id>> {
+        let neighbors = self
+            .edges
+            .get(id)
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))?;
+
+        match neighbors.contains_key(id) {
+            true => Ok(neighbors.len() + 1),
+            false => Ok(neighbors.len()),
+        }
+    }
+}
+
+impl<T: Identifiable, W> Default for UndirectedWeightedGraph<T, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Identifiable, W: Clone> Graph for UndirectedWeightedGraph<T, W> {
+    type Vertex = T;
+    type Weight = W;
+
+    fn vertex(&self, id: &T::Id) -> Option<&T> {
+        self.vertices.get(id)
+    }
+
+    fn vertices(&self) -> impl Iterator<Item = &T> {
+        self.vertices.values()
+    }
+
+    fn vertex_ids(&self) -> impl Iterator<Item = &T::Id> {
+        self.vertices.keys()
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        id: &T::Id,
+    ) -> Result<impl Iterator<Item = (&'a T::Id, &'a W)> + use<'a, T, W>, GraphError<T::Id>> {
+        self.edges
+            .get(id)
+            .map(|weights| weights.iter())
+            .ok_or_else(|| GraphError::VertexNotFound(id.clone()))
+    }
+
+    fn edge(&self, from: &T::Id, to: &T::Id) -> Option<&W> {
+        self.edges.get(from).and_then(|weights| weights.get(to))
+    }
+
+    fn vertex_count(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Return the number of edges in the graph
+    ///
+    /// Note: each edge is stored twice, except self loops
+    fn edge_count(&self) -> usize {
+        let stored_edges: usize = self.edges.values().map(|weights| weights.len()).sum();
+        let self_loops = self
+            .edges
+            .iter()
+            .filter(|(id, weights)| weights.contains_key(id))
+            .count();
+
+        (stored_edges + self_loops) / 2
+    }
+}
+
+impl<T: Identifiable, W: Clone> GraphMut for UndirectedWeightedGraph<T, W> {
+    /// Add a vertex into the graph, and Err if it already exists
+    fn add_vertex(&mut self, vertex: T) -> Result<(), GraphError<T::Id>> {
+        let vertex_id = vertex.get_id().clone();
+
+        // Err if vertex already exists
+        // Or insert the vertex in the graph
+        if self.vertex_exists(&vertex_id) {
+            Err(GraphError::VertexAlreadyExists(vertex_id))
+        } else {
+            self.vertices.insert(vertex_id.clone(), vertex);
+            self.edges.insert(vertex_id, HashMap::new());
+            Ok(())
+        }
+    }
+
+    /// Add an edge into the graph, and Err if it already exists, of if it targets invalid vertices
+    fn add_edge(
+        &mut self,
+        vertex1_id: &T::Id,
+        vertex2_id: &T::Id,
+        weight: W,
+    ) -> Result<(), GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(vertex1_id)?;
+        self.vertex_exists_or_err(vertex2_id)?;
+
+        if self.edge_exists(vertex1_id, vertex2_id) {
+            return Err(GraphError::EdgeAlreadyExists {
+                from: vertex1_id.clone(),
+                to: vertex2_id.clone(),
+            });
+        }
+
+        // Insert the new edge in both directions
+        self.edges
+            .get_mut(vertex1_id)
+            .unwrap()
+            .insert(vertex2_id.clone(), weight.clone());
+        self.edges
+            .get_mut(vertex2_id)
+            .unwrap()
+            .insert(vertex1_id.clone(), weight);
+
+        Ok(())
+    }
+
+    /// Try to remove an edge in both directions and return an Err if it was not present
+    fn remove_edge(
+        &mut self,
+        vertex1_id: &T::Id,
+        vertex2_id: &T::Id,
+    ) -> Result<(), GraphError<T::Id>> {
+        // Check that vertices exist, or err
+        self.vertex_exists_or_err(vertex1_id)?;
+        self.vertex_exists_or_err(vertex2_id)?;
+
+        // Try to remove the value
+        let result = self.edges.get_mut(vertex1_id).unwrap().remove(vertex2_id);
+
+        // If the edge was present and removed, remove its other direction, else Err
+        match result {
+            Some(_) => {
+                self.edges.get_mut(vertex2_id).unwrap().remove(vertex1_id);
+                Ok(())
+            }
+            None => Err(GraphError::EdgeNotFound {
+                from: vertex1_id.clone(),
+                to: vertex2_id.clone(),
+            }),
+        }
+    }
+
+    /// Try to remove a vertex and return an Err if it was not present
+    fn remove_vertex(&mut self, vertex_id: &T::Id) -> Result<(), GraphError<T::Id>> {
+        // Check that vertex exist, or err
+        self.vertex_exists_or_err(vertex_id)?;
+
+        // Remove edges from this vertex
+        let neighbors = self.edges.remove(vertex_id).unwrap();
+
+        // Remove edges to this vertex: as edges are symmetric,
+        // only its neighbors refer to it
+        for neighbor_id in neighbors.keys() {
+            if let Some(weights) = self.edges.get_mut(neighbor_id) {
+                weights.remove(vertex_id);
+            }
+        }
+
+        // Remove the vertex
+        self.vertices.remove(vertex_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::test_utils::{add_cities, City};
+    use super::*;
+
+    fn build_graph() -> Result<UndirectedWeightedGraph<City>, GraphError<String>> {
+        let mut graph = UndirectedWeightedGraph::new();
+        add_cities(
+            &mut graph,
+            &["Tokio", "Paris", "Vancouver"],
+            &[("Tokio", "Paris", 10), ("Tokio", "Vancouver", 7)],
+        )?;
+
+        Ok(graph)
+    }
+
+    #[test]
+    fn test_add_edge() -> Result<(), GraphError<String>> {
+        let mut graph = build_graph()?;
+
+        // Edges should be walkable in both directions, with the same weight
+        assert_eq!(
+            graph.edge(&"Tokio".to_string(), &"Paris".to_string()),
+            Some(&10)
+        );
+        assert_eq!(
+            graph.edge(&"Paris".to_string(), &"Tokio".to_string()),
+            Some(&10)
+        );
+        assert_eq!(graph.edge_count(), 2);
+
+        // Adding an edge in the other direction should Err
+        assert_eq!(
+            graph.add_edge(&"Paris".to_string(), &"Tokio".to_string(), 3),
+            Err(GraphError::EdgeAlreadyExists {
+                from: "Paris".to_string(),
+                to: "Tokio".to_string()
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_degree() -> Result<(), GraphError<String>> {
+        let graph = build_graph()?;
+
+        assert_eq!(graph.degree(&"Tokio".to_string()), Ok(2));
+        assert_eq!(graph.degree(&"Vancouver".to_string()), Ok(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove() -> Result<(), GraphError<String>> {
+        let mut graph = build_graph()?;
+
+        graph.remove_edge(&"Paris".to_string(), &"Tokio".to_string())?;
+        assert_eq!(graph.edge(&"Tokio".to_string(), &"Paris".to_string()), None);
+        assert_eq!(graph.edge_count(), 1);
+
+        graph.remove_vertex(&"Vancouver".to_string())?;
+        assert_eq!(graph.degree(&"Tokio".to_string()), Ok(0));
+        assert_eq!(graph.edge_count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_traversals() -> Result<(), GraphError<String>> {
+        let graph = build_graph()?;
+
+        assert_eq!(
+            graph.traverse_breadth_first(&"Paris".to_string())?,
+            vec![
+                "Paris".to_string(),
+                "Tokio".to_string(),
+                "Vancouver".to_string()
+            ]
+        );
+
+        Ok(())
+    }
+}
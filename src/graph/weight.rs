@@ -0,0 +1,309 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Sub};
+
+/// Trait implemented by the values carried by the edges of weighted graphs
+///
+/// Shortest path algorithms need to sum and compare weights, and to start
+/// from a zero distance. The sums never overflow silently: the algorithms
+/// Err with `GraphError::WeightOverflow` when a result they need does not fit
+/// in the weight type (see `checked_add`).
+///
+/// It is implemented for:
+/// - integer types
+/// - `f64`, through the `OrderedF64` wrapper
+/// - tuples of weights, compared lexicographically, for multi-criteria costs
+///   such as (time, distance)
+///
+/// Note: signed types allow negative weights, which are supported by
+/// Bellman-Ford, Floyd-Warshall and Johnson algorithms. Dijkstra's and A*
+/// algorithms assume weights are positive, and Err on negative weights.
+pub trait Weight: Copy + Ord {
+    /// Return the neutral element of the addition
+    fn zero() -> Self;
+
+    /// Return the sum of two weights, or None if it overflows
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Return the difference of two weights, or None if it overflows
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Return the sum of two weights, bounded to the limits of the type
+    fn saturating_add(self, other: Self) -> Self;
+}
+
+/// Implement `Weight` for integer types
+macro_rules! impl_weight_for_integers {
+    ($($integer:ty),*) => {
+        $(
+            impl Weight for $integer {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$integer>::checked_sub(self, other)
+                }
+
+                fn saturating_add(self, other: Self) -> Self {
+                    <$integer>::saturating_add(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_weight_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Implement `Weight` for tuples of weights, added and subtracted element by element
+/// and compared lexicographically
+macro_rules! impl_weight_for_tuples {
+    ($(($($element:ident $index:tt),+)),*) => {
+        $(
+            impl<$($element: Weight),+> Weight for ($($element,)+) {
+                fn zero() -> Self {
+                    ($($element::zero(),)+)
+                }
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    Some(($(self.$index.checked_add(other.$index)?,)+))
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    Some(($(self.$index.checked_sub(other.$index)?,)+))
+                }
+
+                fn saturating_add(self, other: Self) -> Self {
+                    ($(self.$index.saturating_add(other.$index),)+)
+                }
+            }
+        )*
+    };
+}
+
+impl_weight_for_tuples!((A 0, B 1), (A 0, B 1, C 2), (A 0, B 1, C 2, D 3));
+
+/// A `f64` with a total order, so it can be used as a weight
+///
+/// The values are ordered by `f64::total_cmp`, except that -0.0 equals +0.0
+/// (so it is not a negative weight); NaN is greater than infinity.
+/// Sums overflow to infinity, which `checked_add` reports as None.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrderedF64(pub f64);
+
+impl PartialEq for OrderedF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedF64 {}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Adding +0.0 turns -0.0 into +0.0, and keeps the other values
+        (self.0 + 0.0).total_cmp(&(other.0 + 0.0))
+    }
+}
+
+impl Add for OrderedF64 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        OrderedF64(self.0 + other.0)
+    }
+}
+
+impl Sub for OrderedF64 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        OrderedF64(self.0 - other.0)
+    }
+}
+
+impl From<f64> for OrderedF64 {
+    fn from(value: f64) -> Self {
+        OrderedF64(value)
+    }
+}
+
+impl fmt::Display for OrderedF64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for OrderedF64 {
+    type Err = std::num::ParseFloatError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        value.parse().map(OrderedF64)
+    }
+}
+
+impl Weight for OrderedF64 {
+    fn zero() -> Self {
+        OrderedF64(0.0)
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        let sum = self.0 + other.0;
+
+        // An infinite sum of finite values is an overflow
+        match sum.is_finite() || !(self.0.is_finite() && other.0.is_finite()) {
+            true => Some(OrderedF64(sum)),
+            false => None,
+        }
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(OrderedF64(-other.0))
+    }
+
+    fn saturating_add(self, other: Self) -> Self {
+        let sum = self.0 + other.0;
+
+        match sum.is_finite() || !(self.0.is_finite() && other.0.is_finite()) {
+            true => OrderedF64(sum),
+            false => OrderedF64(sum.clamp(f64::MIN, f64::MAX)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::oriented_weighted_graph::OrientedWeightedGraph;
+    use super::super::{GraphError, GraphMut};
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(u8::zero(), 0);
+        assert_eq!(Weight::checked_add(200u8, 50), Some(250));
+        assert_eq!(Weight::checked_add(200u8, 60), None);
+        assert_eq!(Weight::checked_sub(-100i8, 28), Some(-128));
+        assert_eq!(Weight::checked_sub(-100i8, 29), None);
+        assert_eq!(Weight::saturating_add(200u8, 60), 255);
+        assert_eq!(Weight::saturating_add(-100i8, -100), -128);
+    }
+
+    #[test]
+    fn test_ordered_f64() {
+        let mut weights = vec![OrderedF64(2.5), OrderedF64(-1.0), OrderedF64(f64::INFINITY)];
+        weights.sort();
+        assert_eq!(
+            weights,
+            vec![OrderedF64(-1.0), OrderedF64(2.5), OrderedF64(f64::INFINITY)]
+        );
+
+        assert_eq!(OrderedF64(-0.0), OrderedF64(0.0));
+        assert!(OrderedF64(-0.0) >= OrderedF64::zero());
+        assert!(OrderedF64(-f64::MIN_POSITIVE) < OrderedF64::zero());
+
+        assert_eq!(
+            OrderedF64(0.5).checked_add(OrderedF64(1.0)),
+            Some(OrderedF64(1.5))
+        );
+        assert_eq!(OrderedF64(f64::MAX).checked_add(OrderedF64(f64::MAX)), None);
+        assert_eq!(OrderedF64(f64::MIN).checked_sub(OrderedF64(f64::MAX)), None);
+        assert_eq!(
+            OrderedF64(f64::MAX).saturating_add(OrderedF64(f64::MAX)),
+            OrderedF64(f64::MAX)
+        );
+        assert_eq!(
+            OrderedF64(f64::INFINITY).checked_add(OrderedF64(1.0)),
+            Some(OrderedF64(f64::INFINITY))
+        );
+    }
+
+    #[test]
+    fn test_tuples() {
+        // Compared lexicographically, added element by element
+        assert!((1u32, 100u32) < (2, 0));
+        assert_eq!(<(u32, u32)>::zero(), (0, 0));
+        assert_eq!((1u32, 100u8).checked_add((2, 100)), Some((3, 200)));
+        assert_eq!((1u32, 200u8).checked_add((2, 100)), None);
+        assert_eq!((3u32, 200u8).checked_sub((2, 100)), Some((1, 100)));
+        assert_eq!((1u32, 200u8).checked_sub((2, 100)), None);
+        assert_eq!((1u32, 200u8).saturating_add((2, 100)), (3, 255));
+    }
+
+    #[test]
+    fn test_shortest_path_with_custom_weights() -> Result<(), GraphError<&'static str>> {
+        // Roads weighted by (time, distance): the fastest road wins,
+        // and the shortest one breaks ties
+        let mut graph: OrientedWeightedGraph<&str, (u32, u32)> = OrientedWeightedGraph::new();
+        for city in ["Paris", "Lyon", "Dijon", "Marseille"] {
+            graph.add_vertex(city)?;
+        }
+        graph.add_edge(&"Paris", &"Lyon", (4, 465))?;
+        graph.add_edge(&"Paris", &"Dijon", (3, 315))?;
+        graph.add_edge(&"Dijon", &"Lyon", (1, 195))?;
+        graph.add_edge(&"Lyon", &"Marseille", (3, 315))?;
+
+        let path = graph.shortest_path(&"Paris", &"Marseille")?.unwrap();
+        assert_eq!(path.vertices, vec!["Paris", "Lyon", "Marseille"]);
+        assert_eq!(path.total_weight, (7, 780));
+
+        // Floating point weights
+        let mut graph: OrientedWeightedGraph<&str, OrderedF64> = OrientedWeightedGraph::new();
+        for city in ["Paris", "Lyon", "Dijon"] {
+            graph.add_vertex(city)?;
+        }
+        graph.add_edge(&"Paris", &"Lyon", OrderedF64(4.6))?;
+        graph.add_edge(&"Paris", &"Dijon", OrderedF64(3.1))?;
+        graph.add_edge(&"Dijon", &"Lyon", OrderedF64(1.4))?;
+
+        let path = graph.shortest_path(&"Paris", &"Lyon")?.unwrap();
+        assert_eq!(path.vertices, vec!["Paris", "Dijon", "Lyon"]);
+        assert_eq!(path.total_weight, OrderedF64(3.1) + OrderedF64(1.4));
+
+        // A -0.0 weight is not negative
+        graph.add_edge(&"Lyon", &"Paris", OrderedF64(-0.0))?;
+        assert_eq!(
+            graph
+                .shortest_path(&"Lyon", &"Paris")?
+                .unwrap()
+                .total_weight,
+            OrderedF64(0.0)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflowing_paths() -> Result<(), GraphError<&'static str>> {
+        // The long path overflows, so only the direct edge is a path
+        let mut graph: OrientedWeightedGraph<&str, u8> = OrientedWeightedGraph::new();
+        for city in ["a", "b", "c"] {
+            graph.add_vertex(city)?;
+        }
+        graph.add_edge(&"a", &"b", 200)?;
+        graph.add_edge(&"b", &"c", 100)?;
+        graph.add_edge(&"a", &"c", 250)?;
+
+        let path = graph.shortest_path(&"a", &"c")?.unwrap();
+        assert_eq!(path.vertices, vec!["a", "c"]);
+        assert_eq!(path.total_weight, 250);
+
+        // Without it, the only path overflows
+        graph.remove_edge(&"a", &"c")?;
+        assert_eq!(
+            graph.shortest_path(&"a", &"c"),
+            Err(GraphError::WeightOverflow { from: "b", to: "c" })
+        );
+
+        Ok(())
+    }
+}
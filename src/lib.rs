@@ -7,3 +7,4 @@ pub mod recursion;
 pub mod searching_algorithms;
 pub mod sorting_algorithms;
 pub mod tree;
+pub mod union_find;
@@ -0,0 +1,189 @@
+/// Union-find of the elements 0 to n - 1
+///
+/// Note: elements out of range panic, like an out of bounds vector index
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    /// Parent of each element in its tree (a root is its own parent)
+    parents: Vec<usize>,
+
+    /// Number of elements of each tree (only relevant for the roots)
+    sizes: Vec<usize>,
+
+    /// Number of disjoint sets
+    component_count: usize,
+}
+
+impl UnionFind {
+    /// Instantiate a union-find of `len` elements, each one in its own set
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+            component_count: len,
+        }
+    }
+
+    /// Return the number of elements
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Denote if there is no element
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Add a new element in its own set, and return it
+    pub fn add(&mut self) -> usize {
+        let element = self.parents.len();
+
+        self.parents.push(element);
+        self.sizes.push(1);
+        self.component_count += 1;
+
+        element
+    }
+
+    /// Return the representative of the set of an element
+    pub fn find(&mut self, element: usize) -> usize {
+        let root = self.root(element);
+
+        // Path compression: attach the elements on the way directly to the root
+        let mut current = element;
+        while current != root {
+            let parent = self.parents[current];
+            self.parents[current] = root;
+            current = parent;
+        }
+
+        root
+    }
+
+    /// Merge the sets of two elements, and denote if they were different
+    pub fn union(&mut self, element1: usize, element2: usize) -> bool {
+        let root1 = self.find(element1);
+        let root2 = self.find(element2);
+
+        if root1 == root2 {
+            return false;
+        }
+
+        // Union by size: attach the smallest tree to the biggest one
+        let (small_root, big_root) = match self.sizes[root1] < self.sizes[root2] {
+            true => (root1, root2),
+            false => (root2, root1),
+        };
+        self.parents[small_root] = big_root;
+        self.sizes[big_root] += self.sizes[small_root];
+        self.component_count -= 1;
+
+        true
+    }
+
+    /// Denote if two elements are in the same set
+    pub fn connected(&mut self, element1: usize, element2: usize) -> bool {
+        self.find(element1) == self.find(element2)
+    }
+
+    /// Return the number of disjoint sets
+    pub fn component_count(&self) -> usize {
+        self.component_count
+    }
+
+    /// Return the number of elements in the set of an element
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+
+    /// Iterate over the sets, each one sorted, by order of their smallest element
+    pub fn sets(&self) -> impl Iterator<Item = Vec<usize>> {
+        // Index of the set of each root in the result
+        let mut set_indices: Vec<Option<usize>> = vec![None; self.len()];
+        let mut sets: Vec<Vec<usize>> = Vec::with_capacity(self.component_count);
+
+        for element in 0..self.len() {
+            let root = self.root(element);
+
+            match set_indices[root] {
+                Some(index) => sets[index].push(element),
+                None => {
+                    set_indices[root] = Some(sets.len());
+                    sets.push(vec![element]);
+                }
+            }
+        }
+
+        sets.into_iter()
+    }
+
+    /// Return the root of the tree of an element, without compressing the path
+    fn root(&self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            element = self.parents[element];
+        }
+        element
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let mut union_find = UnionFind::new(3);
+
+        assert_eq!(union_find.len(), 3);
+        assert_eq!(union_find.component_count(), 3);
+        assert!(!union_find.connected(0, 1));
+        assert_eq!(union_find.find(2), 2);
+        assert_eq!(union_find.component_size(2), 1);
+    }
+
+    #[test]
+    fn test_union() {
+        let mut union_find = UnionFind::new(6);
+
+        assert!(union_find.union(0, 1));
+        assert!(union_find.union(2, 1));
+        assert!(union_find.union(3, 4));
+
+        // Already in the same set
+        assert!(!union_find.union(0, 2));
+
+        assert!(union_find.connected(0, 2));
+        assert!(!union_find.connected(0, 3));
+        assert_eq!(union_find.find(0), union_find.find(2));
+        assert_eq!(union_find.component_count(), 3);
+        assert_eq!(union_find.component_size(1), 3);
+        assert_eq!(union_find.component_size(4), 2);
+        assert_eq!(union_find.component_size(5), 1);
+    }
+
+    #[test]
+    fn test_add() {
+        let mut union_find = UnionFind::new(0);
+        assert!(union_find.is_empty());
+
+        assert_eq!(union_find.add(), 0);
+        assert_eq!(union_find.add(), 1);
+        union_find.union(0, 1);
+
+        assert_eq!(union_find.len(), 2);
+        assert_eq!(union_find.component_count(), 1);
+    }
+
+    #[test]
+    fn test_sets() {
+        let mut union_find = UnionFind::new(5);
+        union_find.union(4, 1);
+        union_find.union(0, 3);
+        union_find.union(3, 1);
+
+        assert_eq!(
+            union_find.sets().collect::<Vec<_>>(),
+            vec![vec![0, 1, 3, 4], vec![2]]
+        );
+    }
+}
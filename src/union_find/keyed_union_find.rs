@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use super::dense_union_find::UnionFind;
+
+/// Union-find of hashable keys
+///
+/// Each key is given a dense index (by insertion order), and the sets
+/// are stored by a `UnionFind` of these indices.
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    /// Index of each key
+    indices: HashMap<K, usize>,
+
+    /// Key of each index
+    keys: Vec<K>,
+
+    /// Sets of the key indices
+    union_find: UnionFind,
+}
+
+impl<K: Hash + Eq + Clone> KeyedUnionFind<K> {
+    /// Instantiate an empty union-find
+    pub fn new() -> Self {
+        Self {
+            indices: HashMap::new(),
+            keys: Vec::new(),
+            union_find: UnionFind::new(0),
+        }
+    }
+
+    /// Return the number of keys
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Denote if there is no key
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Denote if a key was added
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Add a key in its own set, and denote if it was not already there
+    pub fn insert(&mut self, key: K) -> bool {
+        if self.contains(&key) {
+            return false;
+        }
+
+        self.index_or_insert(&key);
+        true
+    }
+
+    /// Return the representative of the set of a key,
+    /// or None if the key was not added
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = *self.indices.get(key)?;
+        let root = self.union_find.find(index);
+
+        Some(&self.keys[root])
+    }
+
+    /// Merge the sets of two keys, and denote if they were different
+    ///
+    /// Note: the keys that were not added yet are added first
+    pub fn union(&mut self, key1: &K, key2: &K) -> bool {
+        let index1 = self.index_or_insert(key1);
+        let index2 = self.index_or_insert(key2);
+
+        self.union_find.union(index1, index2)
+    }
+
+    /// Denote if two keys are in the same set
+    /// (keys that were not added are in no set)
+    pub fn connected(&mut self, key1: &K, key2: &K) -> bool {
+        match (self.indices.get(key1), self.indices.get(key2)) {
+            (Some(&index1), Some(&index2)) => self.union_find.connected(index1, index2),
+            _ => false,
+        }
+    }
+
+    /// Return the number of disjoint sets
+    pub fn component_count(&self) -> usize {
+        self.union_find.component_count()
+    }
+
+    /// Return the number of keys in the set of a key,
+    /// or None if the key was not added
+    pub fn component_size(&mut self, key: &K) -> Option<usize> {
+        let index = *self.indices.get(key)?;
+
+        Some(self.union_find.component_size(index))
+    }
+
+    /// Iterate over the sets, keys being sorted by insertion order
+    pub fn sets(&self) -> impl Iterator<Item = Vec<&K>> {
+        self.union_find
+            .sets()
+            .map(|set| set.into_iter().map(|index| &self.keys[index]).collect())
+    }
+
+    /// Return the index of a key, adding it if it is not there yet
+    fn index_or_insert(&mut self, key: &K) -> usize {
+        if let Some(&index) = self.indices.get(key) {
+            return index;
+        }
+
+        let index = self.union_find.add();
+        self.indices.insert(key.clone(), index);
+        self.keys.push(key.clone());
+
+        index
+    }
+}
+
+impl<K: Hash + Eq + Clone> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone> FromIterator<K> for KeyedUnionFind<K> {
+    /// Instantiate a union-find where each key is in its own set
+    fn from_iter<I: IntoIterator<Item = K>>(keys: I) -> Self {
+        let mut union_find = Self::new();
+        for key in keys {
+            union_find.insert(key);
+        }
+        union_find
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let mut union_find = KeyedUnionFind::new();
+
+        assert!(union_find.insert("Tokio"));
+        assert!(!union_find.insert("Tokio"));
+        assert!(union_find.contains(&"Tokio"));
+        assert_eq!(union_find.find(&"Tokio"), Some(&"Tokio"));
+
+        // Unknown keys are in no set
+        assert_eq!(union_find.find(&"Paris"), None);
+        assert_eq!(union_find.component_size(&"Paris"), None);
+        assert!(!union_find.connected(&"Tokio", &"Paris"));
+    }
+
+    #[test]
+    fn test_union() {
+        let mut union_find: KeyedUnionFind<_> =
+            ["Tokio", "Paris", "Vancouver"].into_iter().collect();
+
+        assert!(union_find.union(&"Tokio", &"Paris"));
+        assert!(!union_find.union(&"Paris", &"Tokio"));
+
+        // Missing keys are added
+        assert!(union_find.union(&"Vancouver", &"Montreal"));
+
+        assert_eq!(union_find.len(), 4);
+        assert_eq!(union_find.component_count(), 2);
+        assert!(union_find.connected(&"Montreal", &"Vancouver"));
+        assert!(!union_find.connected(&"Montreal", &"Tokio"));
+        assert_eq!(union_find.component_size(&"Paris"), Some(2));
+        assert_eq!(
+            union_find.sets().collect::<Vec<_>>(),
+            vec![vec![&"Tokio", &"Paris"], vec![&"Vancouver", &"Montreal"]]
+        );
+    }
+}
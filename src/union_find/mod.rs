@@ -0,0 +1,21 @@
+//! A union-find (or disjoint-set) keeps track of elements split into
+//! disjoint sets. It can merge two sets, and tell if two elements are in
+//! the same set, in almost constant time. It is typically used to find
+//! the connected parts of a graph as its edges are added, for example in
+//! Kruskal's minimum spanning tree algorithm or to detect cycles.
+//!
+//! Each set is stored as a tree, whose root is the representative of the
+//! set. Two optimizations keep the trees flat:
+//! - Union by size: the smallest tree is attached to the root of the biggest one
+//! - Path compression: looking for the root of an element attaches all the
+//!   elements on the way directly to the root
+//!
+//! Two flavours are available:
+//! - `UnionFind` for dense `usize` elements (0 to n - 1), stored in vectors
+//! - `KeyedUnionFind` for any hashable elements, mapped to dense indices
+
+pub mod dense_union_find;
+pub mod keyed_union_find;
+
+pub use dense_union_find::UnionFind;
+pub use keyed_union_find::KeyedUnionFind;